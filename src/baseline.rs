@@ -0,0 +1,141 @@
+use crate::cache::hex_digest;
+use crate::{
+    BranchProtectionReport, ContractError, ContractResult, Finding, MetadataReport,
+    RequiredFilesReport, RuleId, Waiver,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+
+pub const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub findings: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub rule: RuleId,
+    pub target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl Baseline {
+    pub fn from_findings(findings: &[Finding]) -> Self {
+        let mut entries = findings
+            .iter()
+            .filter(|finding| !finding.passed && !finding.is_waived())
+            .map(|finding| BaselineEntry {
+                fingerprint: finding.fingerprint(),
+                rule: finding.rule,
+                target: finding.target.clone(),
+                path: finding.path.clone(),
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|left, right| {
+            (left.rule.as_str(), &left.target, &left.path).cmp(&(
+                right.rule.as_str(),
+                &right.target,
+                &right.path,
+            ))
+        });
+        entries.dedup();
+        Self {
+            version: BASELINE_VERSION,
+            findings: entries,
+        }
+    }
+}
+
+pub fn load_baseline(path: &Path) -> ContractResult<Baseline> {
+    let content = std::fs::read_to_string(path)?;
+    let baseline: Baseline = serde_json::from_str(&content)?;
+    if baseline.version != BASELINE_VERSION {
+        return Err(ContractError::InvalidConfig(format!(
+            "unsupported baseline version: {}",
+            baseline.version
+        )));
+    }
+    Ok(baseline)
+}
+
+pub(crate) fn fingerprint(rule: RuleId, target: &str, path: Option<&str>) -> String {
+    hex_digest(format!("{}\0{target}\0{}", rule.as_str(), path.unwrap_or_default()).as_bytes())
+}
+
+pub fn apply_baseline(
+    baseline: &Baseline,
+    branch_reports: &mut [BranchProtectionReport],
+    required_report: Option<&mut RequiredFilesReport>,
+    metadata_report: Option<&mut MetadataReport>,
+) -> Vec<BaselineEntry> {
+    let recorded = baseline
+        .findings
+        .iter()
+        .map(|entry| entry.fingerprint.as_str())
+        .collect::<HashSet<_>>();
+    let mut present = HashSet::new();
+    let mut matches = |rule: RuleId, target: &str, path: Option<&str>| {
+        let fingerprint = fingerprint(rule, target, path);
+        let matched = recorded.contains(fingerprint.as_str());
+        present.insert(fingerprint);
+        matched
+    };
+    let waived = |waiver: Option<&Waiver>| waiver.is_some_and(|waiver| !waiver.expired);
+
+    for report in branch_reports {
+        for detail in report.details.iter_mut().filter(|detail| !detail.passed) {
+            detail.baselined =
+                matches(RuleId::BranchProtection, &report.target, Some(&detail.path))
+                    && !waived(detail.waiver.as_ref());
+        }
+        for check in &mut report.checks {
+            check.baselined = report
+                .details
+                .iter()
+                .any(|detail| detail.baselined && detail.path == check.path);
+        }
+    }
+    if let Some(report) = required_report {
+        for check in report.checks.iter_mut().filter(|check| !check.exists) {
+            check.baselined =
+                matches(RuleId::RequiredFiles, &check.path, None) && !waived(check.waiver.as_ref());
+        }
+        report.recount();
+    }
+    if let Some(report) = metadata_report {
+        for check in report.checks.iter_mut().filter(|check| !check.passed) {
+            check.baselined = matches(RuleId::Metadata, &check.target, Some(&check.path))
+                && !waived(check.waiver.as_ref());
+        }
+    }
+
+    baseline
+        .findings
+        .iter()
+        .filter(|entry| !present.contains(&entry.fingerprint))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fingerprint;
+    use crate::RuleId;
+
+    #[test]
+    fn fingerprint_separates_target_and_path() {
+        assert_ne!(
+            fingerprint(RuleId::BranchProtection, "main", Some("a")),
+            fingerprint(RuleId::BranchProtection, "maina", None)
+        );
+        assert_eq!(
+            fingerprint(RuleId::RequiredFiles, "README.md", None),
+            fingerprint(RuleId::RequiredFiles, "README.md", None)
+        );
+    }
+}
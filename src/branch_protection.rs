@@ -1,10 +1,10 @@
 use crate::{
-    BranchProtection, BranchProtectionRules, ContractError, ContractResult, DiffEntry,
-    RequiredPullRequestReviews, RequiredStatusChecks, StatusCheck, Summary,
+    branch_protection_findings, BranchProtection, BranchProtectionRules, ContractError,
+    ContractResult, DiffEntry, Finding, Forge, ForgeKind, SourceLocation, StatusCheck, Summary,
+    Waiver,
 };
 use globset::{GlobBuilder, GlobSetBuilder};
-use serde::de::DeserializeOwned;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use serde_json::Value;
 use std::collections::HashSet;
 
@@ -15,6 +15,12 @@ pub struct BranchProtectionCheck {
     pub actual: Value,
     pub severity: crate::Severity,
     pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiver: Option<Waiver>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub baselined: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +33,9 @@ pub struct BranchProtectionDetail {
     pub passed: bool,
     pub severity: crate::Severity,
     pub message: String,
+    pub location: Option<SourceLocation>,
+    pub waiver: Option<Waiver>,
+    pub baselined: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -37,145 +46,60 @@ pub struct BranchProtectionReport {
     pub details: Vec<BranchProtectionDetail>,
 }
 
-pub struct GithubClient {
-    base_url: String,
-    token: Option<String>,
-}
-
-impl GithubClient {
-    pub fn new(token: Option<String>) -> Self {
-        Self {
-            base_url: "https://api.github.com".to_string(),
-            token,
-        }
-    }
-
-    pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
-        Self { base_url, token }
-    }
-
-    pub fn list_branches(&self, repo: &str) -> ContractResult<Vec<String>> {
-        let path = format!("/repos/{repo}/branches?per_page=100");
-        let branches: Vec<GithubBranch> = self.get_json(&path)?;
-        Ok(branches.into_iter().map(|branch| branch.name).collect())
-    }
-
-    pub fn get_branch_protection(
-        &self,
-        repo: &str,
-        branch: &str,
-    ) -> ContractResult<Option<BranchProtectionRules>> {
-        let path = format!("/repos/{repo}/branches/{branch}/protection");
-        let response: Option<GithubBranchProtection> = self.get_optional_json(&path)?;
-        Ok(response.map(convert_protection_rules))
-    }
-
-    fn get_optional_json<T: DeserializeOwned>(&self, path: &str) -> ContractResult<Option<T>> {
-        let url = format!(
-            "{}/{}",
-            self.base_url.trim_end_matches('/'),
-            path.trim_start_matches('/')
-        );
-        let mut request = ureq::get(&url)
-            .header("User-Agent", "contract")
-            .header("Accept", "application/vnd.github+json");
-        if let Some(token) = &self.token {
-            request = request.header("Authorization", &format!("Bearer {token}"));
-        }
-        let mut response = match request.call() {
-            Ok(response) => response,
-            Err(ureq::Error::StatusCode(404)) => return Ok(None),
-            Err(ureq::Error::StatusCode(status)) => {
-                return Err(ContractError::GitHubApi(format!("status code {status}")));
-            }
-            Err(error) => return Err(ContractError::GitHubApi(error.to_string())),
-        };
-        let parsed = response
-            .body_mut()
-            .read_json::<T>()
-            .map_err(|error| ContractError::GitHubApi(error.to_string()))?;
-        Ok(Some(parsed))
-    }
-
-    fn get_json<T: DeserializeOwned>(&self, path: &str) -> ContractResult<T> {
-        self.get_optional_json(path)?
-            .ok_or_else(|| ContractError::GitHubApi("GitHub API returned 404".to_string()))
-    }
-}
-
-#[derive(Debug, Deserialize)]
-struct GithubBranch {
-    name: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct GithubBranchProtection {
-    required_pull_request_reviews: Option<GithubPullRequestReviews>,
-    required_status_checks: Option<GithubStatusChecks>,
-    enforce_admins: Option<GithubEnabled>,
-    required_linear_history: Option<GithubEnabled>,
-    allow_force_pushes: Option<GithubEnabled>,
-    allow_deletions: Option<GithubEnabled>,
-    required_conversation_resolution: Option<GithubEnabled>,
-    required_signatures: Option<GithubEnabled>,
-}
-
-#[derive(Debug, Deserialize)]
-struct GithubPullRequestReviews {
-    required_approving_review_count: u8,
-    dismiss_stale_reviews: bool,
-    require_code_owner_reviews: bool,
-    require_last_push_approval: bool,
-}
-
-#[derive(Debug, Deserialize)]
-struct GithubStatusChecks {
-    strict: bool,
-    #[serde(default)]
-    contexts: Vec<String>,
-    #[serde(default)]
-    checks: Vec<GithubStatusCheck>,
-}
-
-#[derive(Debug, Deserialize)]
-struct GithubStatusCheck {
-    context: String,
-    #[serde(default)]
-    app_id: Option<u64>,
+pub fn check_branch_protection(
+    forge: &dyn Forge,
+    repo: &str,
+    config: &BranchProtection,
+) -> ContractResult<Vec<BranchProtectionReport>> {
+    Ok(fetch_branch_protections(forge, repo, &config.branches)?
+        .into_iter()
+        .map(|(target, protection)| {
+            branch_protection_report(target, protection.as_ref(), config, forge.kind())
+        })
+        .collect())
 }
 
-#[derive(Debug, Deserialize)]
-struct GithubEnabled {
-    enabled: bool,
+pub(crate) fn fetch_branch_protections(
+    forge: &dyn Forge,
+    repo: &str,
+    patterns: &[String],
+) -> ContractResult<Vec<(String, Option<BranchProtectionRules>)>> {
+    let branches = forge.list_branches(repo)?;
+    let targets = match_branch_patterns(patterns, &branches)?;
+    let protections = forge.get_branch_protections(repo, &targets)?;
+    Ok(targets.into_iter().zip(protections).collect())
 }
 
-pub fn check_branch_protection(
-    client: &GithubClient,
-    repo: &str,
+pub(crate) fn branch_protection_report(
+    target: String,
+    protection: Option<&BranchProtectionRules>,
     config: &BranchProtection,
-) -> ContractResult<Vec<BranchProtectionReport>> {
-    let branches = client.list_branches(repo)?;
-    let targets = match_branch_patterns(&config.branches, &branches)?;
-    let mut reports = Vec::new();
-    for target in targets {
-        let protection = client.get_branch_protection(repo, &target)?;
-        let details = if let Some(protection) = protection {
-            evaluate_branch_protection(&config.rules, &protection)
-        } else {
-            vec![missing_branch_protection_detail()]
-        };
-        let checks = details
-            .iter()
-            .filter(|detail| !detail.passed)
-            .map(detail_to_check)
-            .collect();
-        reports.push(BranchProtectionReport {
-            target,
-            checks,
-            details,
-        });
+    forge: ForgeKind,
+) -> BranchProtectionReport {
+    let mut details = if let Some(protection) = protection {
+        let unsupported = forge.unsupported_branch_protection_fields();
+        let mut details = evaluate_branch_protection(&config.rules, protection);
+        details.retain(|detail| !unsupported.contains(&detail.path.as_str()));
+        details
+    } else {
+        vec![missing_branch_protection_detail()]
+    };
+    for detail in &mut details {
+        detail.location = config.location_for(&detail.path).cloned();
+        if let Some(severity) = config.severity_for(&target, &detail.path) {
+            detail.severity = severity;
+        }
+    }
+    let checks = details
+        .iter()
+        .filter(|detail| !detail.passed)
+        .map(detail_to_check)
+        .collect();
+    BranchProtectionReport {
+        target,
+        checks,
+        details,
     }
-    Ok(reports)
 }
 
 pub fn summarize_branch_protection(reports: &[BranchProtectionReport]) -> Summary {
@@ -185,6 +109,14 @@ pub fn summarize_branch_protection(reports: &[BranchProtectionReport]) -> Summar
             if detail.passed {
                 continue;
             }
+            if detail.waiver.as_ref().is_some_and(|waiver| !waiver.expired) {
+                summary.waived += 1;
+                continue;
+            }
+            if detail.baselined {
+                summary.baselined += 1;
+                continue;
+            }
             match detail.severity {
                 crate::Severity::Error => summary.error += 1,
                 crate::Severity::Warning => summary.warning += 1,
@@ -196,31 +128,11 @@ pub fn summarize_branch_protection(reports: &[BranchProtectionReport]) -> Summar
 }
 
 pub fn diff_branch_protection(reports: &[BranchProtectionReport]) -> Vec<DiffEntry> {
-    let mut diffs = Vec::new();
-    for report in reports {
-        for detail in &report.details {
-            if detail.passed {
-                continue;
-            }
-            let diff_type = if detail.missing.is_some() || detail.extra.is_some() {
-                "array_diff"
-            } else {
-                "value_mismatch"
-            };
-            diffs.push(DiffEntry {
-                rule: "branch_protection".to_string(),
-                path: detail.path.clone(),
-                diff_type: diff_type.to_string(),
-                severity: None,
-                target: Some(report.target.clone()),
-                expected: Some(detail.expected.clone()),
-                actual: Some(detail.actual.clone()),
-                missing: detail.missing.clone(),
-                extra: detail.extra.clone(),
-            });
-        }
-    }
-    diffs
+    branch_protection_findings(reports)
+        .iter()
+        .filter(|finding| !finding.passed)
+        .map(Finding::to_diff_entry)
+        .collect()
 }
 
 fn missing_branch_protection_detail() -> BranchProtectionDetail {
@@ -233,6 +145,9 @@ fn missing_branch_protection_detail() -> BranchProtectionDetail {
         passed: false,
         severity: crate::Severity::Error,
         message: "Branch protection is not enabled".to_string(),
+        location: None,
+        waiver: None,
+        baselined: false,
     }
 }
 
@@ -243,6 +158,9 @@ fn detail_to_check(detail: &BranchProtectionDetail) -> BranchProtectionCheck {
         actual: detail.actual.clone(),
         severity: detail.severity,
         message: detail.message.clone(),
+        location: detail.location.clone(),
+        waiver: detail.waiver.clone(),
+        baselined: detail.baselined,
     }
 }
 
@@ -268,91 +186,7 @@ fn match_branch_patterns(patterns: &[String], branches: &[String]) -> ContractRe
         .collect())
 }
 
-fn convert_protection_rules(protection: GithubBranchProtection) -> BranchProtectionRules {
-    BranchProtectionRules {
-        required_pull_request_reviews: convert_pull_request_reviews(
-            protection.required_pull_request_reviews,
-        ),
-        required_status_checks: convert_status_checks(protection.required_status_checks),
-        enforce_admins: protection
-            .enforce_admins
-            .map(|value| value.enabled)
-            .unwrap_or(false),
-        required_linear_history: protection
-            .required_linear_history
-            .map(|value| value.enabled)
-            .unwrap_or(false),
-        allow_force_pushes: protection
-            .allow_force_pushes
-            .map(|value| value.enabled)
-            .unwrap_or(false),
-        allow_deletions: protection
-            .allow_deletions
-            .map(|value| value.enabled)
-            .unwrap_or(false),
-        required_conversation_resolution: protection
-            .required_conversation_resolution
-            .map(|value| value.enabled)
-            .unwrap_or(false),
-        required_signatures: protection
-            .required_signatures
-            .map(|value| value.enabled)
-            .unwrap_or(false),
-    }
-}
-
-fn convert_pull_request_reviews(
-    reviews: Option<GithubPullRequestReviews>,
-) -> RequiredPullRequestReviews {
-    if let Some(reviews) = reviews {
-        RequiredPullRequestReviews {
-            enabled: true,
-            required_approving_review_count: reviews.required_approving_review_count,
-            dismiss_stale_reviews: reviews.dismiss_stale_reviews,
-            require_code_owner_reviews: reviews.require_code_owner_reviews,
-            require_last_push_approval: reviews.require_last_push_approval,
-        }
-    } else {
-        RequiredPullRequestReviews {
-            enabled: false,
-            required_approving_review_count: 0,
-            dismiss_stale_reviews: false,
-            require_code_owner_reviews: false,
-            require_last_push_approval: false,
-        }
-    }
-}
-
-fn convert_status_checks(checks: Option<GithubStatusChecks>) -> RequiredStatusChecks {
-    if let Some(checks) = checks {
-        let mut result = Vec::new();
-        for context in checks.contexts {
-            result.push(StatusCheck {
-                context,
-                app_id: None,
-            });
-        }
-        for check in checks.checks {
-            result.push(StatusCheck {
-                context: check.context,
-                app_id: check.app_id,
-            });
-        }
-        RequiredStatusChecks {
-            enabled: true,
-            strict: checks.strict,
-            checks: result,
-        }
-    } else {
-        RequiredStatusChecks {
-            enabled: false,
-            strict: false,
-            checks: Vec::new(),
-        }
-    }
-}
-
-fn evaluate_branch_protection(
+pub(crate) fn evaluate_branch_protection(
     expected: &BranchProtectionRules,
     actual: &BranchProtectionRules,
 ) -> Vec<BranchProtectionDetail> {
@@ -520,6 +354,9 @@ fn evaluate_branch_protection(
                 passed,
                 severity,
                 message,
+                location: None,
+                waiver: None,
+                baselined: false,
             });
         }
     }
@@ -618,6 +455,9 @@ fn push_detail(
         passed,
         severity,
         message: if passed { String::new() } else { message },
+        location: None,
+        waiver: None,
+        baselined: false,
     });
 }
 
@@ -654,6 +494,7 @@ fn has_status_check(expected: &StatusCheck, actual: &[StatusCheck]) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::RequiredStatusChecks;
 
     fn status_check(context: &str) -> StatusCheck {
         StatusCheck {
@@ -0,0 +1,84 @@
+use crate::ContractResult;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Distinguishes temporary files of concurrent writers within one process.
+static NEXT_TEMPORARY: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    directory: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedResponse {
+    pub(crate) url: String,
+    pub(crate) identity: String,
+    pub(crate) etag: String,
+    pub(crate) body: String,
+}
+
+impl ResponseCache {
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    pub fn default_directory() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CACHE_HOME")
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .filter(|value| !value.is_empty())
+                    .map(|home| PathBuf::from(home).join(".cache"))
+            })?;
+        Some(base.join("repo-contract"))
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub(crate) fn load(&self, url: &str, identity: &str) -> Option<CachedResponse> {
+        let content = std::fs::read_to_string(self.entry_path(url, identity)).ok()?;
+        let entry: CachedResponse = serde_json::from_str(&content).ok()?;
+        (entry.url == url && entry.identity == identity).then_some(entry)
+    }
+
+    pub(crate) fn store(&self, entry: &CachedResponse) -> ContractResult<()> {
+        std::fs::create_dir_all(&self.directory)?;
+        let path = self.entry_path(&entry.url, &entry.identity);
+        let temporary = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            NEXT_TEMPORARY.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = std::fs::write(&temporary, serde_json::to_string(entry)?)
+            .and_then(|()| std::fs::rename(&temporary, path));
+        if written.is_err() {
+            let _ = std::fs::remove_file(&temporary);
+        }
+        Ok(written?)
+    }
+
+    fn entry_path(&self, url: &str, identity: &str) -> PathBuf {
+        let key = hex_digest(format!("{identity}\n{url}").as_bytes());
+        self.directory.join(format!("{key}.json"))
+    }
+}
+
+pub(crate) fn token_identity(token: Option<&str>) -> String {
+    match token {
+        Some(token) => hex_digest(token.as_bytes()),
+        None => "anonymous".to_string(),
+    }
+}
+
+pub(crate) fn hex_digest(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
@@ -0,0 +1,82 @@
+use crate::{ContractResult, GithubClient, Summary};
+use serde::Serialize;
+use serde_json::json;
+
+pub const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckConclusion {
+    Success,
+    Neutral,
+    Failure,
+}
+
+impl CheckConclusion {
+    pub fn from_summary(summary: &Summary, strict: bool) -> Self {
+        if summary.error > 0 || (strict && summary.warning > 0) {
+            CheckConclusion::Failure
+        } else if summary.warning > 0 {
+            CheckConclusion::Neutral
+        } else {
+            CheckConclusion::Success
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnnotationLevel {
+    Notice,
+    Warning,
+    Failure,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckAnnotation {
+    pub path: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub annotation_level: AnnotationLevel,
+    pub title: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckRun {
+    pub name: String,
+    pub head_sha: String,
+    pub conclusion: CheckConclusion,
+    pub title: String,
+    pub summary: String,
+    pub annotations: Vec<CheckAnnotation>,
+}
+
+pub fn publish_check_run(
+    client: &GithubClient,
+    repo: &str,
+    check_run: &CheckRun,
+) -> ContractResult<u64> {
+    let mut batches = check_run.annotations.chunks(MAX_ANNOTATIONS_PER_REQUEST);
+    let output = |annotations: &[CheckAnnotation]| {
+        json!({
+            "title": check_run.title,
+            "summary": check_run.summary,
+            "annotations": annotations,
+        })
+    };
+    let id = client.create_check_run(
+        repo,
+        &json!({
+            "name": check_run.name,
+            "head_sha": check_run.head_sha,
+            "status": "completed",
+            "conclusion": check_run.conclusion,
+            "output": output(batches.next().unwrap_or_default()),
+        }),
+    )?;
+    for batch in batches {
+        client.update_check_run(repo, id, &json!({ "output": output(batch) }))?;
+    }
+    Ok(id)
+}
@@ -1,4 +1,5 @@
 use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use repo_contract::ForgeKind;
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -10,6 +11,16 @@ pub(crate) struct Cli {
     pub(crate) verbose: u8,
     #[arg(long = "no-color", default_value_t = false)]
     pub(crate) no_color: bool,
+    #[arg(long = "no-cache", global = true, default_value_t = false)]
+    pub(crate) no_cache: bool,
+    #[arg(long = "cache-dir", global = true, value_name = "PATH")]
+    pub(crate) cache_dir: Option<PathBuf>,
+    #[arg(short = 'j', long = "jobs", global = true, value_name = "N")]
+    pub(crate) jobs: Option<usize>,
+    #[arg(long = "forge", global = true, value_name = "KIND")]
+    pub(crate) forge: Option<ForgeKind>,
+    #[arg(long = "api-url", global = true, value_name = "URL")]
+    pub(crate) api_url: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -17,9 +28,14 @@ pub(crate) enum Commands {
     Validate(ValidateArgs),
     Check(CheckArgs),
     Diff(DiffArgs),
+    Explain(ExplainArgs),
+    Migrate(MigrateArgs),
+    Report(ReportArgs),
+    Plan(PlanArgs),
     Apply(ApplyArgs),
     Init(InitArgs),
-    Schema,
+    Schema(SchemaArgs),
+    Snapshot(SnapshotArgs),
 }
 
 #[derive(clap::Args)]
@@ -30,6 +46,10 @@ pub(crate) struct ValidateArgs {
     pub(crate) config: Option<PathBuf>,
     #[arg(short = 'p', long = "with-profile", default_value_t = false)]
     pub(crate) with_profile: bool,
+    #[arg(long = "merged", default_value_t = false)]
+    pub(crate) merged: bool,
+    #[arg(long = "print-effective", value_name = "FORMAT", requires = "merged")]
+    pub(crate) print_effective: Option<EffectiveFormat>,
     #[arg(short = 'f', long = "format")]
     pub(crate) format: Option<ValidateFormat>,
     #[arg(short = 'q', long = "quiet", default_value_t = false)]
@@ -42,6 +62,14 @@ pub(crate) struct CheckArgs {
     pub(crate) config: Option<PathBuf>,
     #[arg(short = 'r', long = "remote")]
     pub(crate) remote: Option<String>,
+    #[arg(long = "state", value_name = "PATH", conflicts_with = "remote")]
+    pub(crate) state: Option<PathBuf>,
+    #[arg(
+        long = "remote-files",
+        default_value_t = false,
+        conflicts_with = "state"
+    )]
+    pub(crate) remote_files: bool,
     #[arg(long = "rules")]
     pub(crate) rules: Option<String>,
     #[arg(short = 'f', long = "format")]
@@ -50,6 +78,18 @@ pub(crate) struct CheckArgs {
     pub(crate) strict: Option<bool>,
     #[arg(short = 'q', long = "quiet", default_value_t = false)]
     pub(crate) quiet: bool,
+    #[arg(long = "publish-check-run", default_value_t = false)]
+    pub(crate) publish_check_run: bool,
+    #[arg(long = "head-sha", value_name = "SHA", requires = "publish_check_run")]
+    pub(crate) head_sha: Option<String>,
+    #[arg(long = "baseline", value_name = "PATH")]
+    pub(crate) baseline: Option<PathBuf>,
+    #[arg(
+        long = "write-baseline",
+        value_name = "PATH",
+        conflicts_with = "baseline"
+    )]
+    pub(crate) write_baseline: Option<PathBuf>,
 }
 
 #[derive(clap::Args)]
@@ -58,6 +98,14 @@ pub(crate) struct DiffArgs {
     pub(crate) config: Option<PathBuf>,
     #[arg(short = 'r', long = "remote")]
     pub(crate) remote: Option<String>,
+    #[arg(long = "state", value_name = "PATH", conflicts_with = "remote")]
+    pub(crate) state: Option<PathBuf>,
+    #[arg(
+        long = "remote-files",
+        default_value_t = false,
+        conflicts_with = "state"
+    )]
+    pub(crate) remote_files: bool,
     #[arg(long = "rules")]
     pub(crate) rules: Option<String>,
     #[arg(short = 'f', long = "format")]
@@ -65,9 +113,63 @@ pub(crate) struct DiffArgs {
 }
 
 #[derive(clap::Args)]
-pub(crate) struct ApplyArgs {
+pub(crate) struct ReportArgs {
+    #[arg(short = 'c', long = "config")]
+    pub(crate) config: Option<PathBuf>,
+    #[arg(short = 'r', long = "remote")]
+    pub(crate) remote: Option<String>,
+    #[arg(long = "state", value_name = "PATH", conflicts_with = "remote")]
+    pub(crate) state: Option<PathBuf>,
+    #[arg(
+        long = "remote-files",
+        default_value_t = false,
+        conflicts_with = "state"
+    )]
+    pub(crate) remote_files: bool,
+    #[arg(long = "rules")]
+    pub(crate) rules: Option<String>,
+    #[arg(long = "pr", value_name = "NUMBER")]
+    pub(crate) pr: u64,
+    #[arg(short = 's', long = "strict", action = ArgAction::SetTrue)]
+    pub(crate) strict: Option<bool>,
+}
+
+#[derive(clap::Args)]
+pub(crate) struct PlanArgs {
+    #[arg(short = 'c', long = "config")]
+    pub(crate) config: Option<PathBuf>,
+    #[arg(short = 'r', long = "remote")]
+    pub(crate) remote: Option<String>,
+    #[arg(long = "remote-files", default_value_t = false)]
+    pub(crate) remote_files: bool,
+    #[arg(long = "rules")]
+    pub(crate) rules: Option<String>,
+    #[arg(short = 'o', long = "out", default_value = "plan.json")]
+    pub(crate) out: PathBuf,
+}
+
+#[derive(clap::Args)]
+pub(crate) struct ExplainArgs {
     #[arg(short = 'c', long = "config")]
     pub(crate) config: Option<PathBuf>,
+    #[arg(short = 'f', long = "format")]
+    pub(crate) format: Option<ExplainFormat>,
+}
+
+#[derive(clap::Args)]
+pub(crate) struct MigrateArgs {
+    #[arg(value_name = "PATH")]
+    pub(crate) path: Option<PathBuf>,
+    #[arg(short = 'c', long = "config")]
+    pub(crate) config: Option<PathBuf>,
+    #[arg(short = 'w', long = "write", default_value_t = false)]
+    pub(crate) write: bool,
+}
+
+#[derive(clap::Args)]
+pub(crate) struct ApplyArgs {
+    #[arg(value_name = "PLAN")]
+    pub(crate) plan: PathBuf,
 }
 
 #[derive(clap::Args)]
@@ -84,16 +186,51 @@ pub(crate) struct InitArgs {
     pub(crate) force: bool,
 }
 
+#[derive(clap::Args)]
+pub(crate) struct SchemaArgs {
+    #[arg(long = "result", default_value_t = false)]
+    pub(crate) result: bool,
+    #[arg(
+        long = "contract-version",
+        value_name = "VERSION",
+        conflicts_with = "result"
+    )]
+    pub(crate) contract_version: Option<String>,
+}
+
+#[derive(clap::Args)]
+pub(crate) struct SnapshotArgs {
+    #[arg(short = 'r', long = "remote")]
+    pub(crate) remote: Option<String>,
+}
+
 #[derive(Clone, Debug, ValueEnum)]
 pub(crate) enum ValidateFormat {
     Human,
     Json,
 }
 
+#[derive(Clone, Debug, ValueEnum)]
+pub(crate) enum EffectiveFormat {
+    Yaml,
+    Json,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub(crate) enum ExplainFormat {
+    Human,
+    Json,
+}
+
 #[derive(Clone, Debug, ValueEnum)]
 pub(crate) enum CheckFormat {
     Human,
     Json,
+    Sarif,
+    Junit,
+    Github,
+    Markdown,
+    Html,
 }
 
 #[derive(Clone, Debug, ValueEnum)]
@@ -101,10 +238,12 @@ pub(crate) enum DiffFormat {
     Human,
     Json,
     Yaml,
+    Junit,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum Rule {
     RequiredFiles,
     BranchProtection,
+    Metadata,
 }
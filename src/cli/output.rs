@@ -1,4 +1,26 @@
-use repo_contract::{BranchProtectionReport, DiffEntry, DiffReport, RequiredFilesReport, Summary};
+use repo_contract::{
+    branch_protection_descriptor, metadata_descriptor, rule_descriptor, BranchProtectionReport,
+    CheckResult, DiffEntry, DiffReport, DiffType, MetadataReport, Plan, PlanAction, PlannedChange,
+    RequiredFilesReport, Summary, Waiver,
+};
+use std::path::Path;
+
+mod check_run;
+mod diagnostic;
+mod github;
+mod html;
+mod junit;
+mod markdown;
+mod sarif;
+
+use diagnostic::{render_diagnostic, Diagnostic};
+
+pub(super) use check_run::build_check_run;
+pub(super) use github::print_check_github;
+pub(super) use html::print_check_html;
+pub(super) use junit::print_junit;
+pub(super) use markdown::{print_check_markdown, render_markdown};
+pub(super) use sarif::print_check_sarif;
 
 pub(super) fn print_validate_human(reports: &[repo_contract::ValidationReport]) {
     let mut errors = 0;
@@ -7,8 +29,18 @@ pub(super) fn print_validate_human(reports: &[repo_contract::ValidationReport])
             println!("✓ {}: Valid", report.path);
         } else {
             println!("✗ {}: Invalid", report.path);
+            let descriptor = rule_descriptor("E020").expect("E020 is defined");
             for issue in &report.errors {
-                println!("  - {}", issue.message);
+                println!(
+                    "{}",
+                    render_diagnostic(&Diagnostic {
+                        severity: repo_contract::Severity::Error,
+                        descriptor,
+                        location: issue.location.as_ref(),
+                        label: issue.message.clone(),
+                        help: descriptor.help.to_string(),
+                    })
+                );
             }
             errors += report.errors.len();
         }
@@ -27,10 +59,77 @@ pub(super) fn print_validate_json(
     Ok(())
 }
 
+pub(super) fn print_explain_human(fields: &[repo_contract::ExplainedField]) {
+    let rendered = fields
+        .iter()
+        .map(|field| {
+            let origin = match (&field.origin, &field.tier_preset) {
+                (Some(location), None) => {
+                    format!("{}:{}", display_path(&location.file), location.line)
+                }
+                (Some(location), Some(tier)) => format!(
+                    "{}:{} (tier preset {tier})",
+                    display_path(&location.file),
+                    location.line
+                ),
+                (None, Some(tier)) => format!("tier preset {tier}"),
+                (None, None) => "default".to_string(),
+            };
+            (
+                format!("{} = {}", field.path, format_check_value(&field.value)),
+                origin,
+            )
+        })
+        .collect::<Vec<_>>();
+    let width = rendered
+        .iter()
+        .map(|(line, _)| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (line, origin) in rendered {
+        println!("{line:<width$}  # {origin}");
+    }
+}
+
+pub(super) fn print_migration_diff(path: &Path, outcome: &repo_contract::MigrationOutcome) {
+    let display = display_path(path);
+    println!("--- {display} ({})", outcome.from);
+    println!("+++ {display} ({})", outcome.to);
+    let original = outcome.original.lines().collect::<Vec<_>>();
+    let migrated = outcome.migrated.lines().collect::<Vec<_>>();
+    let mut common = vec![vec![0usize; migrated.len() + 1]; original.len() + 1];
+    for (i, old) in original.iter().enumerate().rev() {
+        for (j, new) in migrated.iter().enumerate().rev() {
+            common[i][j] = if old == new {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    while i < original.len() || j < migrated.len() {
+        if i < original.len() && j < migrated.len() && original[i] == migrated[j] {
+            println!(" {}", original[i]);
+            i += 1;
+            j += 1;
+        } else if i < original.len()
+            && (j == migrated.len() || common[i + 1][j] >= common[i][j + 1])
+        {
+            println!("-{}", original[i]);
+            i += 1;
+        } else {
+            println!("+{}", migrated[j]);
+            j += 1;
+        }
+    }
+}
+
 pub(super) fn print_check_human(
     branch_reports: &[BranchProtectionReport],
     report: Option<&RequiredFilesReport>,
-    summary: &Summary,
+    metadata_report: Option<&MetadataReport>,
+    result: &CheckResult,
 ) {
     for report in branch_reports {
         println!("Branch Protection [{}]", report.target);
@@ -46,12 +145,14 @@ pub(super) fn print_check_human(
                     format_check_value(&detail.expected)
                 );
             } else {
-                let icon = match detail.severity {
-                    repo_contract::Severity::Error => "✗",
-                    repo_contract::Severity::Warning => "⚠",
-                    repo_contract::Severity::Info => "ℹ",
-                };
-                println!("  {icon} {}: {}", detail.path, detail.message);
+                println!(
+                    "  {} {}: {}{}{}",
+                    failure_icon(detail.severity, detail.waiver.as_ref(), detail.baselined),
+                    detail.path,
+                    detail.message,
+                    waiver_suffix(detail.waiver.as_ref()),
+                    baseline_suffix(detail.baselined)
+                );
             }
         }
         println!();
@@ -59,50 +160,202 @@ pub(super) fn print_check_human(
     if let Some(report) = report {
         println!("Required Files");
         for check in &report.checks {
-            let (icon, message) = if check.exists {
-                ("✓", "Found")
+            if check.exists {
+                println!("  ✓ {}: Found", check.path);
             } else {
-                match check.severity {
-                    repo_contract::Severity::Error => ("✗", "Not found (error)"),
-                    repo_contract::Severity::Warning => ("⚠", "Not found (warning)"),
-                    repo_contract::Severity::Info => ("ℹ", "Not found (info)"),
-                }
-            };
-            println!("  {icon} {}: {message}", check.path);
+                println!(
+                    "  {} {}: Not found ({}){}{}",
+                    failure_icon(check.severity, check.waiver.as_ref(), check.baselined),
+                    check.path,
+                    check.severity.as_str(),
+                    waiver_suffix(check.waiver.as_ref()),
+                    baseline_suffix(check.baselined)
+                );
+            }
         }
     }
-    println!(
-        "Summary: {} error, {} warning, {} info",
+    if let Some(metadata) = metadata_report.filter(|metadata| !metadata.checks.is_empty()) {
+        if report.is_some() {
+            println!();
+        }
+        println!("Metadata");
+        for check in &metadata.checks {
+            if check.passed {
+                let label = match check.path.as_str() {
+                    "maintainers" => "exists",
+                    "maintainers.write_access" => "has write access",
+                    _ => "up to date",
+                };
+                println!("  ✓ {}: {label}", check.target);
+            } else {
+                println!(
+                    "  {} {}{}{}",
+                    failure_icon(check.severity, check.waiver.as_ref(), check.baselined),
+                    check.message,
+                    waiver_suffix(check.waiver.as_ref()),
+                    baseline_suffix(check.baselined)
+                );
+            }
+        }
+    }
+    if !result.fixed.is_empty() {
+        if report.is_some() || metadata_report.is_some() {
+            println!();
+        }
+        println!("Fixed since baseline");
+        for entry in &result.fixed {
+            match &entry.path {
+                Some(path) => println!("  ✓ {} {} ({path})", entry.rule.as_str(), entry.target),
+                None => println!("  ✓ {} {}", entry.rule.as_str(), entry.target),
+            }
+        }
+    }
+    print_check_diagnostics(branch_reports, report, metadata_report);
+    println!("Summary: {}", format_summary(&result.summary));
+}
+
+fn failure_icon(
+    severity: repo_contract::Severity,
+    waiver: Option<&Waiver>,
+    baselined: bool,
+) -> &'static str {
+    if waiver.is_some_and(|waiver| !waiver.expired) {
+        return "⊘";
+    }
+    if baselined {
+        return "○";
+    }
+    match severity {
+        repo_contract::Severity::Error => "✗",
+        repo_contract::Severity::Warning => "⚠",
+        repo_contract::Severity::Info => "ℹ",
+    }
+}
+
+fn waiver_suffix(waiver: Option<&Waiver>) -> String {
+    waiver
+        .map(|waiver| format!(" [{}]", waiver.describe()))
+        .unwrap_or_default()
+}
+
+fn baseline_suffix(baselined: bool) -> &'static str {
+    if baselined {
+        " [baseline]"
+    } else {
+        ""
+    }
+}
+
+fn format_summary(summary: &Summary) -> String {
+    let mut text = format!(
+        "{} error, {} warning, {} info",
         summary.error, summary.warning, summary.info
     );
+    if summary.waived > 0 {
+        text.push_str(&format!(", {} waived", summary.waived));
+    }
+    if summary.baselined > 0 {
+        text.push_str(&format!(", {} baselined", summary.baselined));
+    }
+    text
 }
 
-pub(super) fn print_check_json(
+fn print_check_diagnostics(
     branch_reports: &[BranchProtectionReport],
     report: Option<&RequiredFilesReport>,
-    summary: &Summary,
-    valid: bool,
-) -> anyhow::Result<()> {
-    let mut results = Vec::new();
+    metadata_report: Option<&MetadataReport>,
+) {
+    let mut diagnostics = Vec::new();
     for report in branch_reports {
-        results.push(serde_json::json!({
-            "rule": "branch_protection",
-            "target": report.target,
-            "checks": report.checks,
-        }));
+        for detail in report.details.iter().filter(|detail| !detail.passed) {
+            if detail.baselined || detail.waiver.as_ref().is_some_and(|waiver| !waiver.expired) {
+                continue;
+            }
+            let descriptor = branch_protection_descriptor(&detail.path);
+            let label = if detail.missing.is_some() || detail.extra.is_some() {
+                detail.message.clone()
+            } else if detail.path == "branch_protection" {
+                "not protected".to_string()
+            } else {
+                format!(
+                    "expected {}, got {}",
+                    format_check_value(&detail.expected),
+                    format_check_value(&detail.actual)
+                )
+            };
+            diagnostics.push(Diagnostic {
+                severity: detail.severity,
+                descriptor,
+                location: detail.location.as_ref(),
+                label: format!(
+                    "{label} on {}{}",
+                    report.target,
+                    waiver_suffix(detail.waiver.as_ref())
+                ),
+                help: descriptor.help.to_string(),
+            });
+        }
     }
     if let Some(report) = report {
-        results.push(serde_json::json!({
-            "rule": "required_files",
-            "checks": report.checks,
-        }));
+        for check in &report.checks {
+            if check.exists
+                || check.baselined
+                || check.waiver.as_ref().is_some_and(|waiver| !waiver.expired)
+            {
+                continue;
+            }
+            let Some(descriptor) = rule_descriptor(&check.code) else {
+                continue;
+            };
+            let (label, help) = match (descriptor.code, check.severity) {
+                ("E002", _) => (
+                    "no file matches this pattern".to_string(),
+                    descriptor.help.to_string(),
+                ),
+                (_, repo_contract::Severity::Error) => (
+                    "file not found in repository".to_string(),
+                    format!("Create {} or change severity to \"warning\"", check.path),
+                ),
+                _ => (
+                    "file not found in repository".to_string(),
+                    format!("Create {} or one of its alternatives", check.path),
+                ),
+            };
+            diagnostics.push(Diagnostic {
+                severity: check.severity,
+                descriptor,
+                location: check.location.as_ref(),
+                label: format!("{label}{}", waiver_suffix(check.waiver.as_ref())),
+                help,
+            });
+        }
     }
-    let output = serde_json::json!({
-        "valid": valid,
-        "results": results,
-        "summary": summary,
-    });
-    println!("{}", serde_json::to_string_pretty(&output)?);
+    if let Some(report) = metadata_report {
+        for check in report.checks.iter().filter(|check| !check.passed) {
+            if check.baselined || check.waiver.as_ref().is_some_and(|waiver| !waiver.expired) {
+                continue;
+            }
+            let descriptor = metadata_descriptor(&check.path);
+            diagnostics.push(Diagnostic {
+                severity: check.severity,
+                descriptor,
+                location: check.location.as_ref(),
+                label: format!("{}{}", check.message, waiver_suffix(check.waiver.as_ref())),
+                help: descriptor.help.to_string(),
+            });
+        }
+    }
+    if diagnostics.is_empty() {
+        return;
+    }
+    println!();
+    for diagnostic in &diagnostics {
+        println!("{}", render_diagnostic(diagnostic));
+    }
+}
+
+pub(super) fn print_result_json(result: &CheckResult) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(result)?);
     Ok(())
 }
 
@@ -114,6 +367,7 @@ pub(super) fn print_diff_human(report: Option<&DiffReport>) {
         }
         let mut branch_groups: Vec<(String, Vec<&DiffEntry>)> = Vec::new();
         let mut required_diffs = Vec::new();
+        let mut metadata_diffs = Vec::new();
         for diff in &report.diffs {
             if diff.rule == "branch_protection" {
                 let target = diff.target.clone().unwrap_or_else(|| "unknown".to_string());
@@ -125,41 +379,43 @@ pub(super) fn print_diff_human(report: Option<&DiffReport>) {
                 }
             } else if diff.rule == "required_files" {
                 required_diffs.push(diff);
+            } else if diff.rule == "metadata" {
+                metadata_diffs.push(diff);
             }
         }
 
         for (target, diffs) in branch_groups {
             println!("Branch Protection [{target}]");
             for diff in diffs {
-                if diff.diff_type == "array_diff" {
-                    println!("  {}:", diff.path);
-                    if let Some(missing) = &diff.missing {
-                        for value in missing {
-                            println!("    + {value} (missing)");
-                        }
-                    }
-                    if let Some(extra) = &diff.extra {
-                        for value in extra {
-                            println!("    - {value} (extra)");
-                        }
-                    }
-                } else {
-                    println!(
-                        "  {}: expected {}, got {}",
-                        diff.path,
-                        format_diff_value(diff.expected.as_ref()),
-                        format_diff_value(diff.actual.as_ref())
-                    );
-                }
+                print_branch_protection_diff(diff, "  ");
             }
             println!();
         }
 
         if !required_diffs.is_empty() {
             println!("Required Files:");
-            for diff in required_diffs {
+            for diff in &required_diffs {
                 let severity = diff.severity.map(|value| value.as_str()).unwrap_or("error");
-                println!("  + {} (missing, severity: {severity})", diff.path);
+                println!(
+                    "  + {} (missing, severity: {severity}){}",
+                    diff.path,
+                    waiver_suffix(diff.waiver.as_ref())
+                );
+            }
+        }
+
+        if !metadata_diffs.is_empty() {
+            if !required_diffs.is_empty() {
+                println!();
+            }
+            println!("Metadata:");
+            for diff in metadata_diffs {
+                let target = diff.target.as_deref().unwrap_or_default();
+                println!(
+                    "  ! {target} ({}){}",
+                    diff.path,
+                    waiver_suffix(diff.waiver.as_ref())
+                );
             }
         }
     } else {
@@ -167,22 +423,21 @@ pub(super) fn print_diff_human(report: Option<&DiffReport>) {
     }
 }
 
-pub(super) fn print_diff_json(report: Option<&DiffReport>) -> anyhow::Result<()> {
-    let output = serde_json::json!({
-        "diffs": report.map(|report| report.diffs.clone()).unwrap_or_default(),
-    });
-    println!("{}", serde_json::to_string_pretty(&output)?);
+pub(super) fn print_result_yaml(result: &CheckResult) -> anyhow::Result<()> {
+    println!("{}", serde_yaml::to_string(result)?);
     Ok(())
 }
 
-pub(super) fn print_diff_yaml(report: Option<&DiffReport>) -> anyhow::Result<()> {
-    let output = serde_yaml::to_string(
-        &report
-            .map(|report| report.diffs.clone())
-            .unwrap_or_default(),
-    )?;
-    println!("{output}");
-    Ok(())
+fn display_path(path: &Path) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|current| path.strip_prefix(current).ok())
+        .unwrap_or(path);
+    let display = relative.to_string_lossy().replace('\\', "/");
+    display
+        .strip_prefix("./")
+        .map(str::to_string)
+        .unwrap_or(display)
 }
 
 fn format_check_value(value: &serde_json::Value) -> String {
@@ -192,6 +447,73 @@ fn format_check_value(value: &serde_json::Value) -> String {
     }
 }
 
+pub(super) fn print_plan_human(plan: &Plan, path: &Path) {
+    if plan.changes.is_empty() && plan.manual.is_empty() {
+        println!("No changes. {} matches the contract.", plan.repository);
+    }
+    for change in &plan.changes {
+        let action = match change.action {
+            PlanAction::Create => "create",
+            PlanAction::Update => "update",
+        };
+        println!("{} ({action})", plan_change_title(change));
+        for diff in &change.diffs {
+            print_branch_protection_diff(diff, "  ");
+        }
+        println!();
+    }
+    if !plan.manual.is_empty() {
+        println!("Manual changes (not applied):");
+        for diff in &plan.manual {
+            println!("  + {} ({})", diff.path, diff.rule);
+        }
+        println!();
+    }
+    println!(
+        "Plan: {} to change, {} manual. Saved to {}",
+        plan.changes.len(),
+        plan.manual.len(),
+        path.display()
+    );
+}
+
+pub(super) fn plan_change_title(change: &PlannedChange) -> String {
+    let kind = match change.rule.as_str() {
+        "settings" => "Repository Settings",
+        "labels" => "Label",
+        _ => "Branch Protection",
+    };
+    format!("{kind} [{}]", change.target)
+}
+
+fn print_branch_protection_diff(diff: &DiffEntry, indent: &str) {
+    if diff.diff_type == DiffType::ArrayDiff {
+        println!(
+            "{indent}{}:{}",
+            diff.path,
+            waiver_suffix(diff.waiver.as_ref())
+        );
+        if let Some(missing) = &diff.missing {
+            for value in missing {
+                println!("{indent}  + {value} (missing)");
+            }
+        }
+        if let Some(extra) = &diff.extra {
+            for value in extra {
+                println!("{indent}  - {value} (extra)");
+            }
+        }
+    } else {
+        println!(
+            "{indent}{}: expected {}, got {}{}",
+            diff.path,
+            format_diff_value(diff.expected.as_ref()),
+            format_diff_value(diff.actual.as_ref()),
+            waiver_suffix(diff.waiver.as_ref())
+        );
+    }
+}
+
 fn format_diff_value(value: Option<&serde_json::Value>) -> String {
     value
         .map(format_check_value)
@@ -0,0 +1,54 @@
+use super::display_path;
+use super::github::collect_annotations;
+use super::markdown::render_markdown;
+use repo_contract::{
+    AnnotationLevel, CheckAnnotation, CheckConclusion, CheckResult, CheckRun, Severity,
+};
+use std::path::Path;
+
+const CHECK_RUN_NAME: &str = "Repo Contract";
+
+pub(in crate::cli) fn build_check_run(
+    result: &CheckResult,
+    strict: bool,
+    config_path: &Path,
+    head_sha: String,
+) -> anyhow::Result<CheckRun> {
+    let summary = &result.summary;
+    let conclusion = CheckConclusion::from_summary(summary, strict);
+    let annotations = collect_annotations(result)
+        .into_iter()
+        .map(|annotation| {
+            let (path, line) = match annotation.location {
+                Some(location) => (display_path(&location.file), location.line),
+                None => (display_path(config_path), 1),
+            };
+            CheckAnnotation {
+                path,
+                start_line: line,
+                end_line: line,
+                annotation_level: match annotation.severity {
+                    Severity::Error => AnnotationLevel::Failure,
+                    Severity::Warning => AnnotationLevel::Warning,
+                    Severity::Info => AnnotationLevel::Notice,
+                },
+                title: format!(
+                    "{} {}",
+                    annotation.descriptor.code, annotation.descriptor.name
+                ),
+                message: annotation.message,
+            }
+        })
+        .collect();
+    Ok(CheckRun {
+        name: CHECK_RUN_NAME.to_string(),
+        head_sha,
+        conclusion,
+        title: format!(
+            "{} error, {} warning, {} info",
+            summary.error, summary.warning, summary.info
+        ),
+        summary: render_markdown(result)?,
+        annotations,
+    })
+}
@@ -0,0 +1,60 @@
+use super::display_path;
+use repo_contract::{RuleDescriptor, Severity, SourceFile, SourceLocation};
+
+pub(super) struct Diagnostic<'a> {
+    pub(super) severity: Severity,
+    pub(super) descriptor: &'a RuleDescriptor,
+    pub(super) location: Option<&'a SourceLocation>,
+    pub(super) label: String,
+    pub(super) help: String,
+}
+
+pub(super) fn render_diagnostic(diagnostic: &Diagnostic<'_>) -> String {
+    let mut output = format!(
+        "{}[{}]: {}\n",
+        diagnostic.severity.as_str(),
+        diagnostic.descriptor.code,
+        diagnostic.descriptor.description
+    );
+    let Some(location) = diagnostic.location else {
+        output.push_str(&format!("  = {}\n", diagnostic.label));
+        output.push_str(&format!("  = help: {}\n", diagnostic.help));
+        return output;
+    };
+    let width = location.line.to_string().len().max(2);
+    let gutter = " ".repeat(width + 1);
+    output.push_str(&format!(
+        "{}--> {}:{}:{}\n",
+        " ".repeat(width),
+        display_path(&location.file),
+        location.line,
+        location.column
+    ));
+    let source_line = SourceFile::load(&location.file)
+        .ok()
+        .and_then(|source| source.line(location.line).map(str::to_string));
+    output.push_str(&format!("{gutter}|\n"));
+    if let Some(source_line) = source_line {
+        let start = location.column.saturating_sub(1).min(source_line.len());
+        let rest = &source_line[start..];
+        let skipped = match rest.strip_prefix("- ") {
+            Some(item) => rest.len() - item.trim_start().len(),
+            None => 0,
+        };
+        let marked = rest[skipped..].trim_end();
+        output.push_str(&format!(
+            "{:>width$} | {source_line}\n",
+            location.line,
+            width = width
+        ));
+        output.push_str(&format!(
+            "{gutter}| {}{} {}\n",
+            " ".repeat(start + skipped),
+            "^".repeat(marked.chars().count().max(1)),
+            diagnostic.label
+        ));
+        output.push_str(&format!("{gutter}|\n"));
+    }
+    output.push_str(&format!("{gutter}= help: {}\n", diagnostic.help));
+    output
+}
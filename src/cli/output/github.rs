@@ -0,0 +1,130 @@
+use super::markdown::render_markdown;
+use super::{display_path, format_summary};
+use anyhow::Context;
+use repo_contract::{
+    rule_descriptor, CheckResult, RuleDescriptor, RuleId, Severity, SourceLocation,
+};
+use std::io::Write;
+use std::path::Path;
+
+pub(super) struct Annotation<'a> {
+    pub(super) severity: Severity,
+    pub(super) descriptor: &'static RuleDescriptor,
+    pub(super) message: String,
+    pub(super) location: Option<&'a SourceLocation>,
+}
+
+pub(in crate::cli) fn print_check_github(result: &CheckResult) -> anyhow::Result<()> {
+    for annotation in collect_annotations(result) {
+        println!("{}", workflow_command(&annotation));
+    }
+    let summary = &result.summary;
+    println!("Summary: {}", format_summary(summary));
+
+    if let Some(path) = std::env::var_os("GITHUB_OUTPUT") {
+        let outputs = format!(
+            "valid={}\nerror-count={}\nwarning-count={}\ninfo-count={}\nwaived-count={}\nbaselined-count={}\nresult-json={}\n",
+            result.valid,
+            summary.error,
+            summary.warning,
+            summary.info,
+            summary.waived,
+            summary.baselined,
+            serde_json::to_string(result)?
+        );
+        append(Path::new(&path), &outputs)?;
+    }
+    if let Some(path) = std::env::var_os("GITHUB_STEP_SUMMARY") {
+        append(Path::new(&path), &render_markdown(result)?)?;
+    }
+    Ok(())
+}
+
+pub(super) fn collect_annotations(result: &CheckResult) -> Vec<Annotation<'_>> {
+    result
+        .failures()
+        .map(|finding| {
+            let mut message = match (finding.rule, finding.severity) {
+                (RuleId::BranchProtection, _) => {
+                    format!("[{}] {}", finding.target, finding.message)
+                }
+                (RuleId::Metadata, _) => finding.message.clone(),
+                (RuleId::RequiredFiles, Severity::Error) => {
+                    format!("Missing required file: {}", finding.target)
+                }
+                (RuleId::RequiredFiles, Severity::Warning) => {
+                    format!("File not found: {} (severity: warning)", finding.target)
+                }
+                (RuleId::RequiredFiles, Severity::Info) => {
+                    format!("File not found: {} (info)", finding.target)
+                }
+            };
+            if let Some(waiver) = &finding.waiver {
+                message.push_str(&format!(" [{}]", waiver.describe()));
+            }
+            if finding.baselined {
+                message.push_str(" [baseline]");
+            }
+            Annotation {
+                severity: if finding.is_waived() || finding.baselined {
+                    Severity::Info
+                } else {
+                    finding.severity
+                },
+                descriptor: rule_descriptor(&finding.code).expect("finding code is defined"),
+                message,
+                location: finding.location.as_ref(),
+            }
+        })
+        .collect()
+}
+
+fn workflow_command(annotation: &Annotation<'_>) -> String {
+    let command = match annotation.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "notice",
+    };
+    let mut properties = Vec::new();
+    if let Some(location) = annotation.location {
+        properties.push(format!(
+            "file={}",
+            escape_property(&display_path(&location.file))
+        ));
+        properties.push(format!("line={}", location.line));
+        properties.push(format!("col={}", location.column));
+    }
+    properties.push(format!(
+        "title={}",
+        escape_property(&format!(
+            "{} {}",
+            annotation.descriptor.code, annotation.descriptor.name
+        ))
+    ));
+    format!(
+        "::{command} {}::{}",
+        properties.join(","),
+        escape_data(&annotation.message)
+    )
+}
+
+fn append(path: &Path, content: &str) -> anyhow::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("{} を開けませんでした", path.display()))?;
+    file.write_all(content.as_bytes())
+        .with_context(|| format!("{} への書き込みに失敗しました", path.display()))
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
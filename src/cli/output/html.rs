@@ -0,0 +1,157 @@
+use super::format_check_value;
+use repo_contract::{CheckResult, Contract, Finding, RuleId, Severity};
+
+const STYLE: &str = "body{font-family:-apple-system,BlinkMacSystemFont,\"Segoe UI\",Helvetica,Arial,sans-serif;margin:2rem auto;max-width:960px;color:#24292f}\
+h1{font-size:1.6rem}h2{font-size:1.25rem;border-bottom:1px solid #d0d7de;padding-bottom:.3rem;margin-top:2rem}\
+table{border-collapse:collapse;width:100%;margin:.5rem 0 1rem}th,td{border:1px solid #d0d7de;padding:.35rem .6rem;text-align:left;vertical-align:top}\
+th{background:#f6f8fa}code,pre{font-family:SFMono-Regular,Consolas,monospace;font-size:.85rem}\
+pre{background:#f6f8fa;padding:1rem;overflow:auto}\
+.badge{display:inline-block;border-radius:1rem;padding:.1rem .6rem;font-size:.8rem;font-weight:600;color:#fff}\
+.pass{background:#28a745}.waived{background:#6e7781}.error{background:#d73a49}.warning{background:#bf8700}.info{background:#0969da}";
+
+pub(in crate::cli) fn print_check_html(
+    result: &CheckResult,
+    contract: &Contract,
+) -> anyhow::Result<()> {
+    let summary = &result.summary;
+    let mut body = String::new();
+    body.push_str(&format!(
+        "<h1>Repo Contract Compliance Report {}</h1>\n",
+        if result.valid {
+            badge("pass", "PASSED")
+        } else {
+            badge("error", "FAILED")
+        }
+    ));
+    body.push_str(
+        "<table>\n<tr><th>Errors</th><th>Warnings</th><th>Info</th><th>Waived</th><th>Baselined</th></tr>\n",
+    );
+    body.push_str(&format!(
+        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n</table>\n",
+        summary.error, summary.warning, summary.info, summary.waived, summary.baselined
+    ));
+
+    let findings = |rule: RuleId| {
+        result
+            .findings
+            .iter()
+            .filter(move |finding| finding.rule == rule)
+    };
+    let mut branches: Vec<&str> = Vec::new();
+    for finding in findings(RuleId::BranchProtection) {
+        if !branches.contains(&finding.target.as_str()) {
+            branches.push(&finding.target);
+        }
+    }
+    for branch in branches {
+        body.push_str(&format!(
+            "<h2>Branch Protection <code>{}</code></h2>\n",
+            escape(branch)
+        ));
+        body.push_str(
+            "<table>\n<tr><th>Setting</th><th>Expected</th><th>Actual</th><th>Result</th></tr>\n",
+        );
+        for finding in findings(RuleId::BranchProtection).filter(|finding| finding.target == branch)
+        {
+            body.push_str(&format!(
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape(finding.path.as_deref().unwrap_or("")),
+                escape(&format_check_value(&finding.expected)),
+                escape(&format_check_value(&finding.actual)),
+                result_badge(finding)
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    if findings(RuleId::RequiredFiles).next().is_some() {
+        body.push_str("<h2>Required Files</h2>\n");
+        body.push_str(
+            "<table>\n<tr><th>Path</th><th>Description</th><th>Severity</th><th>Result</th></tr>\n",
+        );
+        for finding in findings(RuleId::RequiredFiles) {
+            body.push_str(&format!(
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape(&finding.target),
+                escape(finding.description.as_deref().unwrap_or("")),
+                finding.severity.as_str(),
+                result_badge(finding)
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    if findings(RuleId::Metadata).next().is_some() {
+        body.push_str("<h2>Metadata</h2>\n");
+        body.push_str(
+            "<table>\n<tr><th>Check</th><th>Target</th><th>Message</th><th>Result</th></tr>\n",
+        );
+        for finding in findings(RuleId::Metadata) {
+            body.push_str(&format!(
+                "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td><td>{}</td></tr>\n",
+                escape(finding.path.as_deref().unwrap_or("")),
+                escape(&finding.target),
+                escape(&finding.message),
+                result_badge(finding)
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    body.push_str("<h2>Evaluated Contract</h2>\n");
+    body.push_str(&format!(
+        "<pre>{}</pre>\n",
+        escape(&serde_yaml::to_string(contract)?)
+    ));
+
+    println!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Repo Contract Compliance Report</title>\n<style>{STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>"
+    );
+    Ok(())
+}
+
+fn result_badge(finding: &Finding) -> String {
+    if finding.passed {
+        return badge("pass", "PASS");
+    }
+    if finding.baselined {
+        return badge("waived", "BASELINE");
+    }
+    if let Some(waiver) = &finding.waiver {
+        let label = if waiver.expired {
+            "WAIVER EXPIRED"
+        } else {
+            "WAIVED"
+        };
+        let class = if waiver.expired { "error" } else { "waived" };
+        return format!(
+            "{} <small>{}</small>",
+            badge(class, label),
+            escape(&waiver.describe())
+        );
+    }
+    match finding.severity {
+        Severity::Error => badge("error", "FAIL"),
+        Severity::Warning => badge("warning", "WARNING"),
+        Severity::Info => badge("info", "INFO"),
+    }
+}
+
+fn badge(class: &str, label: &str) -> String {
+    format!("<span class=\"badge {class}\">{label}</span>")
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
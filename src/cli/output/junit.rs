@@ -0,0 +1,166 @@
+use super::format_check_value;
+use repo_contract::{CheckResult, Finding, RuleId, Severity};
+
+struct TestSuite {
+    name: String,
+    cases: Vec<TestCase>,
+}
+
+struct TestCase {
+    classname: String,
+    name: String,
+    outcome: Outcome,
+}
+
+enum Outcome {
+    Passed,
+    Failure { message: String, body: String },
+    Skipped { message: String },
+}
+
+pub(in crate::cli) fn print_junit(result: &CheckResult, strict: bool) {
+    let mut suites: Vec<TestSuite> = Vec::new();
+    for finding in &result.findings {
+        let path = finding.path.clone().unwrap_or_default();
+        let (suite, classname, name) = match finding.rule {
+            RuleId::BranchProtection => (
+                format!("branch_protection [{}]", finding.target),
+                format!("branch_protection.{}", finding.target),
+                path,
+            ),
+            RuleId::RequiredFiles => (
+                "required_files".to_string(),
+                "required_files".to_string(),
+                finding.target.clone(),
+            ),
+            RuleId::Metadata => (
+                "metadata".to_string(),
+                format!("metadata.{path}"),
+                finding.target.clone(),
+            ),
+        };
+        let case = TestCase {
+            classname,
+            name,
+            outcome: outcome(finding, strict),
+        };
+        match suites.iter_mut().find(|existing| existing.name == suite) {
+            Some(existing) => existing.cases.push(case),
+            None => suites.push(TestSuite {
+                name: suite,
+                cases: vec![case],
+            }),
+        }
+    }
+    print!("{}", render(&suites));
+}
+
+/// Every `<failure>` body starts with the same expected / actual / severity lines.
+fn failure_body(expected: &str, actual: &str, severity: Severity) -> String {
+    format!(
+        "expected: {expected}\nactual: {actual}\nseverity: {}",
+        severity.as_str()
+    )
+}
+
+fn outcome(finding: &Finding, strict: bool) -> Outcome {
+    if finding.passed {
+        return Outcome::Passed;
+    }
+    let message = match &finding.waiver {
+        Some(waiver) => format!("{} [{}]", finding.message, waiver.describe()),
+        None if finding.baselined => format!("{} [baseline]", finding.message),
+        None => finding.message.clone(),
+    };
+    if finding.baselined || finding.is_waived() {
+        return Outcome::Skipped { message };
+    }
+    let body = match finding.rule {
+        RuleId::RequiredFiles => {
+            let mut body = failure_body("present", "missing", finding.severity);
+            if let Some(description) = &finding.description {
+                body.push_str(&format!("\ndescription: {description}"));
+            }
+            body
+        }
+        RuleId::BranchProtection | RuleId::Metadata => failure_body(
+            &format_check_value(&finding.expected),
+            &format_check_value(&finding.actual),
+            finding.severity,
+        ),
+    };
+    match finding.severity {
+        Severity::Error => Outcome::Failure { message, body },
+        Severity::Warning if strict => Outcome::Failure { message, body },
+        Severity::Warning | Severity::Info => Outcome::Skipped { message },
+    }
+}
+
+fn render(suites: &[TestSuite]) -> String {
+    let count = |suite: &TestSuite, predicate: fn(&Outcome) -> bool| {
+        suite
+            .cases
+            .iter()
+            .filter(|case| predicate(&case.outcome))
+            .count()
+    };
+    let is_failure = |outcome: &Outcome| matches!(outcome, Outcome::Failure { .. });
+    let is_skipped = |outcome: &Outcome| matches!(outcome, Outcome::Skipped { .. });
+
+    let mut body = String::new();
+    let mut totals = (0, 0, 0);
+    for suite in suites {
+        let failures = count(suite, is_failure);
+        let skipped = count(suite, is_skipped);
+        totals.0 += suite.cases.len();
+        totals.1 += failures;
+        totals.2 += skipped;
+        body.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" skipped=\"{skipped}\">\n",
+            escape(&suite.name),
+            suite.cases.len()
+        ));
+        for case in &suite.cases {
+            let open = format!(
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                escape(&case.classname),
+                escape(&case.name)
+            );
+            match &case.outcome {
+                Outcome::Passed => body.push_str(&format!("{open}/>\n")),
+                Outcome::Failure {
+                    message,
+                    body: text,
+                } => body.push_str(&format!(
+                    "{open}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                    escape(message),
+                    escape(text)
+                )),
+                Outcome::Skipped { message } => body.push_str(&format!(
+                    "{open}>\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                    escape(message)
+                )),
+            }
+        }
+        body.push_str("  </testsuite>\n");
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"repo-contract\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">\n{body}</testsuites>\n",
+        totals.0, totals.1, totals.2
+    )
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
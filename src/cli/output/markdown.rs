@@ -0,0 +1,144 @@
+use super::format_check_value;
+use repo_contract::{CheckResult, Finding, RuleId, Severity, REPORT_MARKER};
+
+const FOOTER: &str =
+    "> 🤖 This comment was generated by [Repo Contract](https://github.com/pirakansa/RepoContract)";
+
+pub(in crate::cli) fn print_check_markdown(result: &CheckResult) -> anyhow::Result<()> {
+    print!("{}", render_markdown(result)?);
+    Ok(())
+}
+
+pub(in crate::cli) fn render_markdown(result: &CheckResult) -> anyhow::Result<String> {
+    let summary = &result.summary;
+    let mut markdown = format!("{REPORT_MARKER}\n");
+    if summary.error == 0 && summary.warning == 0 {
+        markdown.push_str("## ✅ Contract Check Passed\n\nAll contract rules are satisfied.\n\n");
+        markdown.push_str("| Category | Count |\n|----------|-------|\n");
+        markdown.push_str(&format!(
+            "| Errors | {} |\n| Warnings | {} |\n| Info | {} |\n",
+            summary.error, summary.warning, summary.info
+        ));
+        if summary.waived > 0 {
+            markdown.push_str(&format!("| Waived | {} |\n", summary.waived));
+        }
+        if summary.baselined > 0 {
+            markdown.push_str(&format!("| Baselined | {} |\n", summary.baselined));
+        }
+        push_waived(&mut markdown, result);
+        push_fixed(&mut markdown, result);
+    } else {
+        markdown.push_str("## 🔴 Contract Violation Detected\n\n");
+        markdown.push_str("The repository does not comply with the defined contract.\n");
+        for (severity, heading) in [(Severity::Error, "Errors"), (Severity::Warning, "Warnings")] {
+            let findings = result
+                .failures()
+                .filter(|finding| {
+                    finding.severity == severity && !finding.is_waived() && !finding.baselined
+                })
+                .collect::<Vec<_>>();
+            if findings.is_empty() {
+                continue;
+            }
+            markdown.push_str(&format!("\n### {heading} ({})\n\n", findings.len()));
+            markdown.push_str("| Rule | Target | Message |\n|------|--------|---------|\n");
+            for finding in findings {
+                markdown.push_str(&format!(
+                    "| `{}` | `{}` | {} |\n",
+                    finding.rule.as_str(),
+                    finding.target,
+                    escape_cell(&row_message(finding))
+                ));
+            }
+        }
+        push_waived(&mut markdown, result);
+        push_fixed(&mut markdown, result);
+        let json = serde_json::to_string_pretty(result)?;
+        markdown.push_str("\n---\n\n<details>\n<summary>📋 Full Report (JSON)</summary>\n\n");
+        markdown.push_str(&format!("```json\n{json}\n```\n\n</details>\n"));
+    }
+    markdown.push_str(&format!("\n---\n\n{FOOTER}\n"));
+    Ok(markdown)
+}
+
+fn push_waived(markdown: &mut String, result: &CheckResult) {
+    let waived = result
+        .failures()
+        .filter(|finding| finding.is_waived())
+        .collect::<Vec<_>>();
+    if waived.is_empty() {
+        return;
+    }
+    markdown.push_str(&format!("\n### Waived ({})\n\n", waived.len()));
+    markdown.push_str(
+        "| Rule | Target | Reason | Owner | Expires |\n|------|--------|--------|-------|---------|\n",
+    );
+    for finding in waived {
+        let Some(waiver) = &finding.waiver else {
+            continue;
+        };
+        markdown.push_str(&format!(
+            "| `{}` | `{}` | {} | {} | {} |\n",
+            finding.rule.as_str(),
+            finding.target,
+            escape_cell(&waiver.reason),
+            escape_cell(&waiver.owner),
+            waiver.expires
+        ));
+    }
+}
+
+fn push_fixed(markdown: &mut String, result: &CheckResult) {
+    if result.fixed.is_empty() {
+        return;
+    }
+    markdown.push_str(&format!(
+        "\n### Fixed since baseline ({})\n\n",
+        result.fixed.len()
+    ));
+    markdown.push_str("| Rule | Target | Path |\n|------|--------|------|\n");
+    for entry in &result.fixed {
+        markdown.push_str(&format!(
+            "| `{}` | `{}` | {} |\n",
+            entry.rule.as_str(),
+            entry.target,
+            entry
+                .path
+                .as_deref()
+                .map(|path| format!("`{path}`"))
+                .unwrap_or_default()
+        ));
+    }
+}
+
+fn row_message(finding: &Finding) -> String {
+    let message = base_row_message(finding);
+    match &finding.waiver {
+        Some(waiver) => format!("{message} ({})", waiver.describe()),
+        None => message,
+    }
+}
+
+fn base_row_message(finding: &Finding) -> String {
+    match (finding.rule, finding.path.as_deref()) {
+        (RuleId::RequiredFiles, _) => "File not found".to_string(),
+        (RuleId::Metadata, _) => finding.message.clone(),
+        (_, Some(path))
+            if path != "branch_protection"
+                && finding.missing.is_none()
+                && finding.extra.is_none() =>
+        {
+            let key = path.rsplit('.').next().unwrap_or(path);
+            format!(
+                "`{key}`: expected {}, got {}",
+                format_check_value(&finding.expected),
+                format_check_value(&finding.actual)
+            )
+        }
+        _ => finding.message.clone(),
+    }
+}
+
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', "<br>")
+}
@@ -0,0 +1,96 @@
+use super::display_path;
+use repo_contract::{rule_descriptor, CheckResult, Finding, RuleId, Severity, RULE_DESCRIPTORS};
+use serde_json::{json, Value};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = "https://github.com/pirakansa/RepoContract";
+
+pub(in crate::cli) fn print_check_sarif(result: &CheckResult) -> anyhow::Result<()> {
+    let results = result.failures().map(sarif_result).collect::<Vec<_>>();
+
+    let rules = RULE_DESCRIPTORS
+        .iter()
+        .map(|descriptor| {
+            json!({
+                "id": descriptor.code,
+                "name": descriptor.name,
+                "shortDescription": { "text": descriptor.description },
+                "help": { "text": descriptor.help },
+                "properties": { "rule": descriptor.rule },
+            })
+        })
+        .collect::<Vec<_>>();
+    let output = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "repo-contract",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": INFORMATION_URI,
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }],
+    });
+    println!("{}", serde_json::to_string_pretty(&output)?);
+    Ok(())
+}
+
+fn sarif_result(finding: &Finding) -> Value {
+    let descriptor = rule_descriptor(&finding.code).expect("finding code is defined");
+    let waiver = finding.waiver.as_ref();
+    let message = match finding.rule {
+        RuleId::BranchProtection => format!("[{}] {}", finding.target, finding.message),
+        RuleId::RequiredFiles | RuleId::Metadata => finding.message.clone(),
+    };
+    let rule_index = RULE_DESCRIPTORS
+        .iter()
+        .position(|candidate| candidate.code == descriptor.code);
+    let suppressions = waiver.filter(|waiver| !waiver.expired).map(|waiver| {
+        vec![json!({
+            "kind": "external",
+            "status": "accepted",
+            "justification": format!(
+                "{} (owner: {}, expires: {})",
+                waiver.reason, waiver.owner, waiver.expires
+            ),
+        })]
+    });
+    let message = match waiver.filter(|waiver| waiver.expired) {
+        Some(waiver) => format!("{message} [{}]", waiver.describe()),
+        None => message,
+    };
+    let mut result = json!({
+        "ruleId": descriptor.code,
+        "ruleIndex": rule_index,
+        "level": sarif_level(finding.severity),
+        "message": { "text": message },
+        "locations": finding.location.as_ref().map(|location| vec![json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": display_path(&location.file) },
+                "region": {
+                    "startLine": location.line,
+                    "startColumn": location.column,
+                },
+            }
+        })]).unwrap_or_default(),
+    });
+    if let Some(suppressions) = suppressions {
+        result["suppressions"] = json!(suppressions);
+    }
+    if finding.baselined {
+        result["baselineState"] = json!("unchanged");
+    }
+    result
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
@@ -1,39 +1,72 @@
 use super::args::{
-    CheckArgs, CheckFormat, Cli, Commands, DiffArgs, DiffFormat, InitArgs, Rule, ValidateArgs,
-    ValidateFormat,
+    ApplyArgs, CheckArgs, CheckFormat, Cli, Commands, DiffArgs, DiffFormat, EffectiveFormat,
+    ExplainArgs, ExplainFormat, InitArgs, MigrateArgs, PlanArgs, ReportArgs, Rule, SnapshotArgs,
+    ValidateArgs, ValidateFormat,
 };
 use super::output::{
-    print_check_human, print_check_json, print_diff_human, print_diff_json, print_diff_yaml,
-    print_validate_human, print_validate_json,
+    build_check_run, plan_change_title, print_check_github, print_check_html, print_check_human,
+    print_check_markdown, print_check_sarif, print_diff_human, print_explain_human, print_junit,
+    print_migration_diff, print_plan_human, print_result_json, print_result_yaml,
+    print_validate_human, print_validate_json, render_markdown,
 };
 use super::util::{
-    add_summary, branch_protection_reports, profile_path_for, report_profile_name,
-    resolve_config_path, resolve_strict, summarize_required_files,
+    branch_protection_reports, forge_context, github_context, metadata_report, report_profile_name,
+    required_files_report, resolve_config_path, resolve_head_sha, resolve_strict,
+    running_in_github_actions,
 };
 use anyhow::Context;
 use repo_contract::{
-    check_required_files, diff_branch_protection, diff_required_files, init_contract_files,
-    load_config_file, load_contract, resolve_cli_config, schema_json, validate_contract_file,
-    CliConfig, ContractError, LoadOptions,
+    apply_baseline, apply_plan, apply_severity_policy, branch_protection_findings,
+    capture_snapshot, create_plan, diff_branch_protection, diff_required_files, explain_contract,
+    init_contract_files, load_baseline, load_config_file, load_contract, load_plan,
+    metadata_findings, migrate_contract, profile_path_for, publish_check_run,
+    publish_pull_request_report, required_file_findings, resolve_cli_config, result_schema_json,
+    schema_json, schema_json_for_version, validate_contract_file, validate_merged_contract,
+    validate_profile_file, Baseline, BaselineEntry, BranchProtectionReport, CheckResult, CliConfig,
+    CommentAction, Contract, ContractError, ContractFormat, LoadOptions, MetadataReport, PlanScope,
+    RequiredFilesReport, RuleId,
 };
 use std::path::{Path, PathBuf};
 
 pub(super) fn run(cli: Cli) -> anyhow::Result<i32> {
     let config_file = load_config_file(Path::new(".contract.toml"))?;
-    let cli_config = resolve_cli_config(config_file);
+    let mut cli_config = resolve_cli_config(config_file);
+    if cli.no_cache {
+        cli_config.forge_cache = Some(false);
+    }
+    if let Some(cache_dir) = cli.cache_dir {
+        cli_config.forge_cache_dir = Some(cache_dir);
+    }
+    if let Some(jobs) = cli.jobs {
+        cli_config.forge_concurrency = Some(jobs);
+    }
+    if let Some(forge) = cli.forge {
+        cli_config.forge_kind = Some(forge);
+    }
+    if let Some(api_url) = cli.api_url {
+        cli_config.forge_url = Some(api_url);
+    }
     match cli.command {
         Commands::Validate(args) => run_validate(args, &cli_config),
         Commands::Check(args) => run_check(args, &cli_config),
         Commands::Diff(args) => run_diff(args, &cli_config),
-        Commands::Apply(_args) => {
-            eprintln!("apply は Phase 2 で対応予定です。");
-            Ok(2)
-        }
+        Commands::Explain(args) => run_explain(args, &cli_config),
+        Commands::Report(args) => run_report(args, &cli_config),
+        Commands::Plan(args) => run_plan(args, &cli_config),
+        Commands::Apply(args) => run_apply(args, &cli_config),
         Commands::Init(args) => run_init(args),
-        Commands::Schema => {
-            println!("{}", schema_json());
+        Commands::Migrate(args) => run_migrate(args, &cli_config),
+        Commands::Schema(args) => {
+            if args.result {
+                println!("{}", result_schema_json());
+            } else if let Some(version) = args.contract_version {
+                println!("{}", schema_json_for_version(&version)?);
+            } else {
+                println!("{}", schema_json());
+            }
             Ok(0)
         }
+        Commands::Snapshot(args) => run_snapshot(args, &cli_config),
     }
 }
 
@@ -56,7 +89,7 @@ fn run_validate(args: ValidateArgs, cli_config: &CliConfig) -> anyhow::Result<i3
         .with_context(|| format!("{config_path:?} の検証に失敗しました"))?;
     reports.push(report);
 
-    if args.with_profile {
+    if args.with_profile || args.merged {
         let profile_name = report_profile_name(&config_path)?;
         if let Some(profile_name) = profile_name {
             let profile_path = profile_path_for(&config_path, &profile_name);
@@ -64,12 +97,31 @@ fn run_validate(args: ValidateArgs, cli_config: &CliConfig) -> anyhow::Result<i3
                 eprintln!("profile が見つかりません: {}", profile_path.display());
                 return Ok(2);
             }
-            let profile_report = validate_contract_file(&profile_path)?;
+            let profile_report = validate_profile_file(&profile_path, &config_path)?;
             reports.push(profile_report);
         }
     }
 
+    if args.merged && reports.iter().all(|report| report.valid) {
+        let merged_report = validate_merged_contract(&config_path)
+            .with_context(|| format!("{config_path:?} のマージ結果の検証に失敗しました"))?;
+        reports.push(merged_report);
+    }
+
     let valid = reports.iter().all(|report| report.valid);
+    if let (Some(effective_format), true) = (args.print_effective, valid) {
+        let loaded = load_contract(LoadOptions {
+            config_path,
+            include_profile: true,
+        })?;
+        match effective_format {
+            EffectiveFormat::Yaml => print!("{}", serde_yaml::to_string(&loaded.contract)?),
+            EffectiveFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&loaded.contract)?)
+            }
+        }
+        return Ok(0);
+    }
     if args.quiet && valid {
         return Ok(0);
     }
@@ -84,10 +136,6 @@ fn run_validate(args: ValidateArgs, cli_config: &CliConfig) -> anyhow::Result<i3
 
 fn run_check(args: CheckArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
     let rules = parse_rules(args.rules, cli_config.check_rules.clone())?;
-    if args.remote.is_some() && rules.contains(&Rule::RequiredFiles) {
-        eprintln!("remote の required_files チェックは未対応です。");
-        return Ok(2);
-    }
     let config_path = resolve_config_path(None, args.config, cli_config);
     if !config_path.exists() {
         eprintln!(
@@ -100,10 +148,158 @@ fn run_check(args: CheckArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
     let format = args
         .format
         .or_else(|| cli_config.format.as_deref().and_then(parse_check_format))
+        .or_else(|| running_in_github_actions().then_some(CheckFormat::Github))
         .unwrap_or(CheckFormat::Human);
+    let baseline = args
+        .baseline
+        .as_deref()
+        .map(|path| {
+            load_baseline(path)
+                .with_context(|| format!("{} の読み込みに失敗しました", path.display()))
+        })
+        .transpose()?;
+
+    let evaluation = evaluate(
+        &config_path,
+        args.remote.as_deref(),
+        args.state.as_deref(),
+        args.remote_files,
+        &rules,
+        baseline.as_ref(),
+        cli_config,
+    )?;
+    let result = evaluation.result(strict);
+    if let Some(path) = &args.write_baseline {
+        let baseline = Baseline::from_findings(&result.findings);
+        std::fs::write(path, serde_json::to_string_pretty(&baseline)?)
+            .with_context(|| format!("{} への書き込みに失敗しました", path.display()))?;
+        eprintln!(
+            "baseline を書き込みました: {} ({} 件)",
+            path.display(),
+            baseline.findings.len()
+        );
+        return Ok(0);
+    }
+    let Evaluation {
+        contract,
+        branch_reports,
+        required_report: report,
+        metadata_report,
+        ..
+    } = evaluation;
+    let summary = &result.summary;
+    if args.publish_check_run {
+        let check_run = build_check_run(
+            &result,
+            strict,
+            &config_path,
+            resolve_head_sha(args.head_sha)?,
+        )?;
+        let (client, repo) = github_context(args.remote.as_deref(), cli_config)?;
+        let id = publish_check_run(&client, &repo, &check_run)
+            .context("check run の作成に失敗しました")?;
+        eprintln!("check run を作成しました: {repo} (id: {id})");
+    }
+    if args.quiet
+        && summary.error == 0
+        && summary.warning == 0
+        && !matches!(format, CheckFormat::Github)
+    {
+        return Ok(0);
+    }
+
+    match format {
+        CheckFormat::Human => print_check_human(
+            &branch_reports,
+            report.as_ref(),
+            metadata_report.as_ref(),
+            &result,
+        ),
+        CheckFormat::Json => print_result_json(&result)?,
+        CheckFormat::Sarif => print_check_sarif(&result)?,
+        CheckFormat::Junit => print_junit(&result, strict),
+        CheckFormat::Github => print_check_github(&result)?,
+        CheckFormat::Markdown => print_check_markdown(&result)?,
+        CheckFormat::Html => print_check_html(&result, &contract)?,
+    }
+
+    Ok(if result.valid { 0 } else { 1 })
+}
 
+fn run_report(args: ReportArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
+    let rules = parse_rules(args.rules, cli_config.check_rules.clone())?;
+    let config_path = resolve_config_path(None, args.config, cli_config);
+    if !config_path.exists() {
+        eprintln!(
+            "contract ファイルが見つかりません: {}",
+            config_path.display()
+        );
+        return Ok(2);
+    }
+    let strict = resolve_strict(args.strict, cli_config.strict);
+    let evaluation = evaluate(
+        &config_path,
+        args.remote.as_deref(),
+        args.state.as_deref(),
+        args.remote_files,
+        &rules,
+        None,
+        cli_config,
+    )?;
+    let result = evaluation.result(strict);
+    let body = render_markdown(&result)?;
+
+    let (client, repo) = github_context(args.remote.as_deref(), cli_config)?;
+    let published = publish_pull_request_report(&client, &repo, args.pr, &body, &result.summary)
+        .with_context(|| format!("PR #{} への投稿に失敗しました", args.pr))?;
+    let action = match published.comment {
+        CommentAction::Created => "created",
+        CommentAction::Updated => "updated",
+        CommentAction::Unchanged => "unchanged",
+    };
+    println!("Comment: {action} ({repo}#{})", args.pr);
+    for label in &published.removed_labels {
+        println!("Label removed: {label}");
+    }
+    println!("Label: {}", published.label.name());
+
+    Ok(if result.valid { 0 } else { 1 })
+}
+
+struct Evaluation {
+    contract: Contract,
+    branch_reports: Vec<BranchProtectionReport>,
+    required_report: Option<RequiredFilesReport>,
+    metadata_report: Option<MetadataReport>,
+    fixed: Vec<BaselineEntry>,
+}
+
+impl Evaluation {
+    fn result(&self, strict: bool) -> CheckResult {
+        let mut findings = branch_protection_findings(&self.branch_reports);
+        if let Some(report) = &self.required_report {
+            findings.extend(required_file_findings(report));
+        }
+        if let Some(report) = &self.metadata_report {
+            findings.extend(metadata_findings(report));
+        }
+        let mut result = CheckResult::new(findings, strict);
+        result.fixed = self.fixed.clone();
+        result
+    }
+}
+
+fn evaluate(
+    config_path: &Path,
+    remote: Option<&str>,
+    state: Option<&Path>,
+    remote_files: bool,
+    rules: &[Rule],
+    baseline: Option<&Baseline>,
+    cli_config: &CliConfig,
+) -> anyhow::Result<Evaluation> {
     let loaded = load_contract(LoadOptions {
-        config_path: config_path.clone(),
+        config_path: config_path.to_path_buf(),
         include_profile: true,
     })?;
     let root = config_path
@@ -111,45 +307,136 @@ fn run_check(args: CheckArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
         .map(Path::to_path_buf)
         .unwrap_or_else(|| PathBuf::from("."));
 
-    let branch_reports = if rules.contains(&Rule::BranchProtection) {
-        branch_protection_reports(&loaded.contract, args.remote.as_deref(), cli_config)?
+    let mut branch_reports = if rules.contains(&Rule::BranchProtection) {
+        branch_protection_reports(&loaded.contract, remote, state, cli_config)?
     } else {
         Vec::new()
     };
 
-    let report = if rules.contains(&Rule::RequiredFiles) {
-        Some(check_required_files(
+    let mut required_report = if rules.contains(&Rule::RequiredFiles) {
+        Some(required_files_report(
+            &loaded.contract,
             &root,
-            &loaded.contract.required_files,
+            remote,
+            remote_files,
+            cli_config,
         )?)
     } else {
         None
     };
 
-    let mut summary = summarize_required_files(&report);
-    let branch_summary = repo_contract::summarize_branch_protection(&branch_reports);
-    add_summary(&mut summary, &branch_summary);
-    let has_error = summary.error > 0 || (strict && summary.warning > 0);
-    if args.quiet && summary.error == 0 && summary.warning == 0 {
-        return Ok(0);
+    let mut metadata_report = if rules.contains(&Rule::Metadata) {
+        Some(metadata_report(
+            &loaded.contract,
+            remote,
+            state,
+            cli_config,
+        )?)
+    } else {
+        None
+    };
+
+    apply_severity_policy(
+        &loaded.contract.severity_map,
+        &cli_config.severity_map,
+        &loaded.contract.exceptions,
+        &mut branch_reports,
+        required_report.as_mut(),
+        metadata_report.as_mut(),
+    );
+    let mut fixed = baseline
+        .map(|baseline| {
+            apply_baseline(
+                baseline,
+                &mut branch_reports,
+                required_report.as_mut(),
+                metadata_report.as_mut(),
+            )
+        })
+        .unwrap_or_default();
+    fixed.retain(|entry| {
+        rules.contains(&match entry.rule {
+            RuleId::RequiredFiles => Rule::RequiredFiles,
+            RuleId::BranchProtection => Rule::BranchProtection,
+            RuleId::Metadata => Rule::Metadata,
+        })
+    });
+
+    Ok(Evaluation {
+        contract: loaded.contract,
+        branch_reports,
+        required_report,
+        metadata_report,
+        fixed,
+    })
+}
+
+fn run_explain(args: ExplainArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
+    let config_path = resolve_config_path(None, args.config, cli_config);
+    if !config_path.exists() {
+        eprintln!(
+            "contract ファイルが見つかりません: {}",
+            config_path.display()
+        );
+        return Ok(2);
     }
+    let format = args
+        .format
+        .or_else(|| cli_config.format.as_deref().and_then(parse_explain_format))
+        .unwrap_or(ExplainFormat::Human);
 
+    let fields = explain_contract(&config_path)
+        .with_context(|| format!("{config_path:?} の読み込みに失敗しました"))?;
     match format {
-        CheckFormat::Human => print_check_human(&branch_reports, report.as_ref(), &summary),
-        CheckFormat::Json => {
-            print_check_json(&branch_reports, report.as_ref(), &summary, !has_error)?
-        }
+        ExplainFormat::Human => print_explain_human(&fields),
+        ExplainFormat::Json => println!("{}", serde_json::to_string_pretty(&fields)?),
+    }
+    Ok(0)
+}
+
+fn run_migrate(args: MigrateArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
+    let config_path = resolve_config_path(args.path, args.config, cli_config);
+    if !config_path.exists() {
+        eprintln!(
+            "contract ファイルが見つかりません: {}",
+            config_path.display()
+        );
+        return Ok(2);
+    }
+    let content = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("{config_path:?} の読み込みに失敗しました"))?;
+    let outcome = migrate_contract(&content, ContractFormat::from_path(&config_path))
+        .map_err(|error| anyhow::anyhow!("{config_path:?} の移行に失敗しました: {error}"))?;
+    if !outcome.changed() {
+        println!(
+            "{} is already at version {}",
+            config_path.display(),
+            outcome.to
+        );
+        return Ok(0);
     }
 
-    Ok(if has_error { 1 } else { 0 })
+    print_migration_diff(&config_path, &outcome);
+    if args.write {
+        std::fs::write(&config_path, &outcome.migrated)
+            .with_context(|| format!("{config_path:?} の書き込みに失敗しました"))?;
+        println!(
+            "Migrated {}: {} -> {}",
+            config_path.display(),
+            outcome.from,
+            outcome.to
+        );
+    } else {
+        println!(
+            "Migration {} -> {} available (run with --write to apply)",
+            outcome.from, outcome.to
+        );
+    }
+    Ok(0)
 }
 
 fn run_diff(args: DiffArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
     let rules = parse_rules(args.rules, cli_config.check_rules.clone())?;
-    if args.remote.is_some() && rules.contains(&Rule::RequiredFiles) {
-        eprintln!("remote の required_files diff は未対応です。");
-        return Ok(2);
-    }
     let config_path = resolve_config_path(None, args.config, cli_config);
     if !config_path.exists() {
         eprintln!(
@@ -163,6 +450,60 @@ fn run_diff(args: DiffArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
         .or_else(|| cli_config.format.as_deref().and_then(parse_diff_format))
         .unwrap_or(DiffFormat::Human);
 
+    let strict = resolve_strict(None, cli_config.strict);
+    let evaluation = evaluate(
+        &config_path,
+        args.remote.as_deref(),
+        args.state.as_deref(),
+        args.remote_files,
+        &rules,
+        None,
+        cli_config,
+    )?;
+
+    let mut diffs = Vec::new();
+    if let Some(required_report) = &evaluation.required_report {
+        diffs.extend(diff_required_files(&required_report.checks).diffs);
+    }
+    diffs.extend(diff_branch_protection(&evaluation.branch_reports));
+    if let Some(metadata_report) = &evaluation.metadata_report {
+        diffs.extend(
+            metadata_findings(metadata_report)
+                .iter()
+                .filter(|finding| !finding.passed)
+                .map(|finding| finding.to_diff_entry()),
+        );
+    }
+    let summary = evaluation
+        .required_report
+        .as_ref()
+        .map(|report| report.summary.clone());
+    let report = repo_contract::DiffReport { diffs, summary };
+
+    let full_result = evaluation.result(strict);
+    let result = full_result.clone().without_passed();
+    let has_diff = result.summary.error + result.summary.warning + result.summary.info > 0;
+    match format {
+        DiffFormat::Human => print_diff_human(Some(&report)),
+        DiffFormat::Json => print_result_json(&result)?,
+        DiffFormat::Yaml => print_result_yaml(&result)?,
+        DiffFormat::Junit => print_junit(&full_result, strict),
+    }
+
+    Ok(if has_diff { 1 } else { 0 })
+}
+
+fn run_plan(args: PlanArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
+    let rules = parse_rules(args.rules, cli_config.check_rules.clone())?;
+    let config_path = resolve_config_path(None, args.config, cli_config);
+    if !config_path.exists() {
+        eprintln!(
+            "contract ファイルが見つかりません: {}",
+            config_path.display()
+        );
+        return Ok(2);
+    }
+
     let loaded = load_contract(LoadOptions {
         config_path: config_path.clone(),
         include_profile: true,
@@ -172,31 +513,71 @@ fn run_diff(args: DiffArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
         .map(Path::to_path_buf)
         .unwrap_or_else(|| PathBuf::from("."));
 
-    let mut diffs = Vec::new();
-    let summary = if rules.contains(&Rule::RequiredFiles) {
-        let required_report = check_required_files(&root, &loaded.contract.required_files)?;
-        diffs.extend(diff_required_files(&required_report.checks).diffs);
-        Some(required_report.summary)
+    let (forge, repo) = forge_context(args.remote.as_deref(), cli_config)?;
+    let branch_protection = if rules.contains(&Rule::BranchProtection) {
+        loaded.contract.branch_protection.as_ref()
     } else {
         None
     };
-
-    if rules.contains(&Rule::BranchProtection) {
-        let branch_reports =
-            branch_protection_reports(&loaded.contract, args.remote.as_deref(), cli_config)?;
-        diffs.extend(diff_branch_protection(&branch_reports));
+    let scope = PlanScope {
+        branch_protection,
+        settings: loaded.contract.settings.as_ref(),
+        labels: &loaded.contract.labels,
+    };
+    let mut plan =
+        create_plan(forge.as_ref(), &repo, scope).context("plan の作成に失敗しました")?;
+    if rules.contains(&Rule::RequiredFiles) {
+        let required_report = required_files_report(
+            &loaded.contract,
+            &root,
+            args.remote.as_deref(),
+            args.remote_files,
+            cli_config,
+        )?;
+        plan.manual = diff_required_files(&required_report.checks).diffs;
     }
 
-    let report = repo_contract::DiffReport { diffs, summary };
+    std::fs::write(&args.out, serde_json::to_string_pretty(&plan)?)
+        .with_context(|| format!("{} への書き込みに失敗しました", args.out.display()))?;
+    print_plan_human(&plan, &args.out);
 
-    let has_diff = !report.diffs.is_empty();
-    match format {
-        DiffFormat::Human => print_diff_human(Some(&report)),
-        DiffFormat::Json => print_diff_json(Some(&report))?,
-        DiffFormat::Yaml => print_diff_yaml(Some(&report))?,
-    }
+    Ok(if plan.is_empty() && plan.manual.is_empty() {
+        0
+    } else {
+        1
+    })
+}
 
-    Ok(if has_diff { 1 } else { 0 })
+fn run_apply(args: ApplyArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
+    let plan = load_plan(&args.plan)
+        .with_context(|| format!("{} の読み込みに失敗しました", args.plan.display()))?;
+    let mut cli_config = cli_config.clone();
+    cli_config.forge_kind = Some(plan.forge);
+    let (forge, _) = forge_context(Some(&plan.repository), &cli_config)?;
+
+    let applied = match apply_plan(forge.as_ref(), &plan) {
+        Ok(applied) => applied,
+        Err(ContractError::StalePlan(repository)) => {
+            eprintln!(
+                "plan 作成後に {repository} の状態が変更されています。plan を再作成してください。"
+            );
+            return Ok(2);
+        }
+        Err(error) => return Err(error).context("plan の適用に失敗しました"),
+    };
+    if applied.is_empty() {
+        println!("No changes to apply.");
+    }
+    for change in &applied {
+        println!("Applied: {}", plan_change_title(change));
+    }
+    if !plan.manual.is_empty() {
+        eprintln!(
+            "{} 件の変更は自動適用できないため手動で対応してください。",
+            plan.manual.len()
+        );
+    }
+    Ok(0)
 }
 
 fn run_init(args: InitArgs) -> anyhow::Result<i32> {
@@ -228,6 +609,14 @@ fn run_init(args: InitArgs) -> anyhow::Result<i32> {
     }
 }
 
+fn run_snapshot(args: SnapshotArgs, cli_config: &CliConfig) -> anyhow::Result<i32> {
+    let (forge, repo) = forge_context(args.remote.as_deref(), cli_config)?;
+    let snapshot =
+        capture_snapshot(forge.as_ref(), &repo).context("snapshot の取得に失敗しました")?;
+    println!("{}", serde_json::to_string_pretty(&snapshot)?);
+    Ok(0)
+}
+
 fn parse_rules(
     rules: Option<String>,
     config_rules: Option<Vec<String>>,
@@ -253,6 +642,7 @@ fn parse_rules(
         match rule.as_str() {
             "required_files" => parsed.push(Rule::RequiredFiles),
             "branch_protection" => parsed.push(Rule::BranchProtection),
+            "metadata" => parsed.push(Rule::Metadata),
             other => {
                 return Err(ContractError::InvalidConfig(format!(
                     "unknown rule: {other}"
@@ -272,10 +662,23 @@ fn parse_validate_format(value: &str) -> Option<ValidateFormat> {
     }
 }
 
+fn parse_explain_format(value: &str) -> Option<ExplainFormat> {
+    match value {
+        "human" => Some(ExplainFormat::Human),
+        "json" => Some(ExplainFormat::Json),
+        _ => None,
+    }
+}
+
 fn parse_check_format(value: &str) -> Option<CheckFormat> {
     match value {
         "human" => Some(CheckFormat::Human),
         "json" => Some(CheckFormat::Json),
+        "sarif" => Some(CheckFormat::Sarif),
+        "junit" => Some(CheckFormat::Junit),
+        "github" => Some(CheckFormat::Github),
+        "markdown" => Some(CheckFormat::Markdown),
+        "html" => Some(CheckFormat::Html),
         _ => None,
     }
 }
@@ -285,6 +688,7 @@ fn parse_diff_format(value: &str) -> Option<DiffFormat> {
         "human" => Some(DiffFormat::Human),
         "json" => Some(DiffFormat::Json),
         "yaml" => Some(DiffFormat::Yaml),
+        "junit" => Some(DiffFormat::Junit),
         _ => None,
     }
 }
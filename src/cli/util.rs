@@ -1,7 +1,9 @@
 use anyhow::{anyhow, Context};
 use repo_contract::{
-    check_branch_protection, BranchProtectionReport, CliConfig, Contract, GithubClient,
-    RequiredFilesReport, Summary,
+    check_branch_protection, check_metadata, check_remote_required_files, check_required_files,
+    discover_contract, load_snapshot, BranchProtectionReport, CliConfig, Contract, ContractFormat,
+    Forge, ForgeKind, GiteaClient, GithubClient, GitlabClient, MetadataOptions, MetadataReport,
+    RequiredFilesReport, ResponseCache,
 };
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -13,6 +15,11 @@ pub(super) fn resolve_config_path(
 ) -> PathBuf {
     path.or(config)
         .or_else(|| cli_config.config_path.clone())
+        .or_else(|| {
+            std::env::current_dir()
+                .ok()
+                .and_then(|current| discover_contract(&current))
+        })
         .unwrap_or_else(|| PathBuf::from("contract.yml"))
 }
 
@@ -24,49 +31,81 @@ pub(super) fn resolve_strict(flag: Option<bool>, config_strict: Option<bool>) ->
     strict
 }
 
-pub(super) fn report_profile_name(config_path: &Path) -> anyhow::Result<Option<String>> {
-    let content = std::fs::read_to_string(config_path)?;
-    let contract: serde_yaml::Value = serde_yaml::from_str(&content)?;
-    if let Some(profile) = contract.get("profile") {
-        Ok(profile.as_str().map(|value| value.to_string()))
-    } else {
-        Ok(None)
-    }
+pub(super) fn running_in_github_actions() -> bool {
+    env_true("GITHUB_ACTIONS")
 }
 
-pub(super) fn profile_path_for(config_path: &Path, profile: &str) -> PathBuf {
-    config_path
-        .parent()
-        .unwrap_or_else(|| Path::new("."))
-        .join(format!("contract.{profile}.yml"))
-}
-
-pub(super) fn summarize_required_files(report: &Option<RequiredFilesReport>) -> Summary {
-    report
-        .as_ref()
-        .map(|report| report.summary.clone())
-        .unwrap_or_default()
-}
-
-pub(super) fn add_summary(summary: &mut Summary, other: &Summary) {
-    summary.error += other.error;
-    summary.warning += other.warning;
-    summary.info += other.info;
+pub(super) fn report_profile_name(config_path: &Path) -> anyhow::Result<Option<String>> {
+    let content = std::fs::read_to_string(config_path)?;
+    let contract = ContractFormat::from_path(config_path).parse_value(&content)?;
+    Ok(contract
+        .get("profile")
+        .and_then(|profile| profile.as_str())
+        .map(|value| value.to_string()))
 }
 
 pub(super) fn branch_protection_reports(
     contract: &Contract,
     remote: Option<&str>,
+    state: Option<&Path>,
     cli_config: &CliConfig,
 ) -> anyhow::Result<Vec<BranchProtectionReport>> {
     let Some(branch_protection) = contract.branch_protection.as_ref() else {
         return Ok(Vec::new());
     };
-    let (client, repo) = github_context(remote, cli_config)?;
-    check_branch_protection(&client, &repo, branch_protection)
+    if let Some(state) = state {
+        let snapshot =
+            load_snapshot(state).with_context(|| format!("{state:?} の読み込みに失敗しました"))?;
+        return check_branch_protection(&snapshot, &snapshot.repository, branch_protection)
+            .context("branch_protection の評価に失敗しました");
+    }
+    let (forge, repo) = forge_context(remote, cli_config)?;
+    check_branch_protection(forge.as_ref(), &repo, branch_protection)
         .context("branch_protection の取得に失敗しました")
 }
 
+/// Checks required files in the local checkout, or in the forge's default
+/// branch when `remote_files` is set.
+pub(super) fn required_files_report(
+    contract: &Contract,
+    root: &Path,
+    remote: Option<&str>,
+    remote_files: bool,
+    cli_config: &CliConfig,
+) -> anyhow::Result<RequiredFilesReport> {
+    if !remote_files {
+        return Ok(check_required_files(root, &contract.required_files)?);
+    }
+    let (forge, repo) = forge_context(remote, cli_config)?;
+    check_remote_required_files(forge.as_ref(), &repo, &contract.required_files)
+        .context("required_files の取得に失敗しました")
+}
+
+pub(super) fn metadata_report(
+    contract: &Contract,
+    remote: Option<&str>,
+    state: Option<&Path>,
+    cli_config: &CliConfig,
+) -> anyhow::Result<MetadataReport> {
+    let Some(metadata) = contract.metadata.as_ref() else {
+        return Ok(MetadataReport::default());
+    };
+    let options = MetadataOptions {
+        max_age_days: cli_config.metadata_max_age_days,
+    };
+    if metadata.maintainers.is_empty() {
+        return Ok(check_metadata(None, "", metadata, &options)?);
+    }
+    if state.is_some() {
+        return Err(anyhow!(
+            "--state 指定時は metadata ルールの maintainers を検証できません"
+        ));
+    }
+    let (client, repo) = github_context(remote, cli_config)?;
+    check_metadata(Some(&client), &repo, metadata, &options)
+        .context("maintainers の取得に失敗しました")
+}
+
 fn env_true(key: &str) -> bool {
     std::env::var(key)
         .ok()
@@ -74,28 +113,63 @@ fn env_true(key: &str) -> bool {
         .unwrap_or(false)
 }
 
-fn resolve_github_token(cli_config: &CliConfig) -> Option<String> {
-    std::env::var("GITHUB_TOKEN")
+fn env_value(key: &str) -> Option<String> {
+    std::env::var(key)
         .ok()
         .filter(|value| !value.trim().is_empty())
-        .or_else(|| cli_config.github_token.clone())
 }
 
-fn require_github_token(cli_config: &CliConfig) -> anyhow::Result<String> {
-    resolve_github_token(cli_config).ok_or_else(|| {
-        anyhow!("GITHUB_TOKEN または .contract.toml の github.token を設定してください")
+fn resolve_forge_token(kind: ForgeKind, cli_config: &CliConfig) -> Option<String> {
+    env_value(kind.token_env())
+        .or_else(|| cli_config.forge_token.clone())
+        .or_else(|| {
+            (kind == ForgeKind::Github)
+                .then(|| cli_config.github_token.clone())
+                .flatten()
+        })
+}
+
+fn require_forge_token(kind: ForgeKind, cli_config: &CliConfig) -> anyhow::Result<String> {
+    resolve_forge_token(kind, cli_config).ok_or_else(|| match kind {
+        ForgeKind::Github => {
+            anyhow!("GITHUB_TOKEN または .contract.toml の github.token を設定してください")
+        }
+        kind => anyhow!(
+            "{} または .contract.toml の forge.token を設定してください",
+            kind.token_env()
+        ),
     })
 }
 
-fn resolve_repository(remote: Option<&str>) -> anyhow::Result<String> {
+fn resolve_api_url(kind: ForgeKind, cli_config: &CliConfig) -> anyhow::Result<String> {
+    let from_env = match kind {
+        ForgeKind::Github | ForgeKind::Gitea => env_value("GITHUB_API_URL"),
+        ForgeKind::Gitlab => env_value("CI_API_V4_URL"),
+    };
+    cli_config
+        .forge_url
+        .clone()
+        .or(from_env)
+        .or_else(|| kind.default_base_url().map(str::to_string))
+        .ok_or_else(|| {
+            anyhow!(
+                "{} の API URL を --api-url または .contract.toml の forge.url で指定してください",
+                kind.as_str()
+            )
+        })
+}
+
+fn resolve_repository(remote: Option<&str>, kind: ForgeKind) -> anyhow::Result<String> {
     if let Some(remote) = remote {
-        return normalize_repository(remote)
+        return normalize_repository(remote, kind)
             .ok_or_else(|| anyhow!("invalid remote repository: {remote}"));
     }
-    if let Ok(repo) = std::env::var("GITHUB_REPOSITORY") {
-        if !repo.trim().is_empty() {
-            return Ok(repo);
-        }
+    let repository_env = match kind {
+        ForgeKind::Github | ForgeKind::Gitea => "GITHUB_REPOSITORY",
+        ForgeKind::Gitlab => "CI_PROJECT_PATH",
+    };
+    if let Some(repo) = env_value(repository_env) {
+        return Ok(repo);
     }
     let output = Command::new("git")
         .args(["config", "--get", "remote.origin.url"])
@@ -105,31 +179,114 @@ fn resolve_repository(remote: Option<&str>) -> anyhow::Result<String> {
         return Err(anyhow!("git remote.origin.url が見つかりません"));
     }
     let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    normalize_repository(&url).ok_or_else(|| anyhow!("invalid remote repository: {url}"))
+    normalize_repository(&url, kind).ok_or_else(|| anyhow!("invalid remote repository: {url}"))
+}
+
+pub(super) fn forge_context(
+    remote: Option<&str>,
+    cli_config: &CliConfig,
+) -> anyhow::Result<(Box<dyn Forge>, String)> {
+    let kind = cli_config.forge_kind.unwrap_or_default();
+    let repo = resolve_repository(remote, kind).context("リポジトリの解決に失敗しました")?;
+    let token = Some(require_forge_token(kind, cli_config)?);
+    let base_url = resolve_api_url(kind, cli_config)?;
+    let cache = resolve_response_cache(cli_config);
+    let concurrency = cli_config.forge_concurrency;
+    let forge: Box<dyn Forge> = match kind {
+        ForgeKind::Github => Box::new(github_client(token, base_url, cli_config)),
+        ForgeKind::Gitlab => {
+            let mut client = GitlabClient::with_base_url(token, base_url);
+            if let Some(cache) = cache {
+                client = client.with_cache(cache);
+            }
+            if let Some(concurrency) = concurrency {
+                client = client.with_concurrency(concurrency);
+            }
+            Box::new(client)
+        }
+        ForgeKind::Gitea => {
+            let mut client = GiteaClient::with_base_url(token, base_url);
+            if let Some(cache) = cache {
+                client = client.with_cache(cache);
+            }
+            if let Some(concurrency) = concurrency {
+                client = client.with_concurrency(concurrency);
+            }
+            Box::new(client)
+        }
+    };
+    Ok((forge, repo))
 }
 
-fn github_context(
+pub(super) fn github_context(
     remote: Option<&str>,
     cli_config: &CliConfig,
 ) -> anyhow::Result<(GithubClient, String)> {
-    let repo = resolve_repository(remote).context("GitHub リポジトリの解決に失敗しました")?;
-    let token = require_github_token(cli_config)?;
-    Ok((GithubClient::new(Some(token)), repo))
+    let kind = cli_config.forge_kind.unwrap_or_default();
+    if kind != ForgeKind::Github {
+        return Err(anyhow!(
+            "{} には対応していません（GitHub のみ）",
+            kind.as_str()
+        ));
+    }
+    let repo = resolve_repository(remote, kind).context("リポジトリの解決に失敗しました")?;
+    let token = require_forge_token(kind, cli_config)?;
+    let base_url = resolve_api_url(kind, cli_config)?;
+    Ok((github_client(Some(token), base_url, cli_config), repo))
+}
+
+fn github_client(token: Option<String>, base_url: String, cli_config: &CliConfig) -> GithubClient {
+    let mut client = GithubClient::with_base_url(token, base_url);
+    if let Some(cache) = resolve_response_cache(cli_config) {
+        client = client.with_cache(cache);
+    }
+    if let Some(concurrency) = cli_config.forge_concurrency {
+        client = client.with_concurrency(concurrency);
+    }
+    client
 }
 
-fn normalize_repository(value: &str) -> Option<String> {
-    let trimmed = value.trim().trim_end_matches(".git");
-    if let Some(rest) = trimmed.strip_prefix("git@github.com:") {
-        return take_owner_repo(rest);
+pub(super) fn resolve_head_sha(head_sha: Option<String>) -> anyhow::Result<String> {
+    if let Some(head_sha) = head_sha.or_else(|| env_value("GITHUB_SHA")) {
+        return Ok(head_sha);
     }
-    if let Some(rest) = trimmed.strip_prefix("ssh://git@github.com/") {
-        return take_owner_repo(rest);
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .context("HEAD のコミットの取得に失敗しました")?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "HEAD のコミットが見つかりません。--head-sha を指定してください"
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn resolve_response_cache(cli_config: &CliConfig) -> Option<ResponseCache> {
+    if cli_config.forge_cache == Some(false) {
+        return None;
     }
-    if let Some(index) = trimmed.find("github.com/") {
-        let rest = &trimmed[index + "github.com/".len()..];
-        return take_owner_repo(rest);
+    cli_config
+        .forge_cache_dir
+        .clone()
+        .or_else(ResponseCache::default_directory)
+        .map(ResponseCache::new)
+}
+
+fn normalize_repository(value: &str, kind: ForgeKind) -> Option<String> {
+    let trimmed = value.trim().trim_end_matches('/').trim_end_matches(".git");
+    let path = if let Some((_, rest)) = trimmed.split_once("://") {
+        rest.split_once('/').map(|(_, path)| path)?
+    } else {
+        match trimmed.split_once(':') {
+            Some((host, path)) if host.contains('@') => path,
+            _ => trimmed,
+        }
+    };
+    match kind {
+        ForgeKind::Gitlab => take_project_path(path),
+        ForgeKind::Github | ForgeKind::Gitea => take_owner_repo(path),
     }
-    take_owner_repo(trimmed)
 }
 
 fn take_owner_repo(value: &str) -> Option<String> {
@@ -141,3 +298,11 @@ fn take_owner_repo(value: &str) -> Option<String> {
     }
     Some(format!("{owner}/{repo}"))
 }
+
+fn take_project_path(value: &str) -> Option<String> {
+    let parts = value.split('/').collect::<Vec<_>>();
+    if parts.len() < 2 || parts.iter().any(|part| part.is_empty()) {
+        return None;
+    }
+    Some(parts.join("/"))
+}
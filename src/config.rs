@@ -1,4 +1,4 @@
-use crate::ContractResult;
+use crate::{ContractResult, ForgeKind, SeverityMap};
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
 
@@ -10,6 +10,12 @@ pub struct ConfigFile {
     pub check: CheckConfig,
     #[serde(default)]
     pub github: GithubConfig,
+    #[serde(default)]
+    pub forge: ForgeConfig,
+    #[serde(default)]
+    pub metadata: MetadataConfig,
+    #[serde(default)]
+    pub severity_map: SeverityMap,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -27,6 +33,24 @@ pub struct CheckConfig {
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct GithubConfig {
     pub token: Option<String>,
+    pub cache: Option<bool>,
+    pub cache_dir: Option<PathBuf>,
+    pub concurrency: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ForgeConfig {
+    pub kind: Option<ForgeKind>,
+    pub url: Option<String>,
+    pub token: Option<String>,
+    pub cache: Option<bool>,
+    pub cache_dir: Option<PathBuf>,
+    pub concurrency: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct MetadataConfig {
+    pub max_age_days: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -36,6 +60,14 @@ pub struct CliConfig {
     pub strict: Option<bool>,
     pub check_rules: Option<Vec<String>>,
     pub github_token: Option<String>,
+    pub forge_kind: Option<ForgeKind>,
+    pub forge_url: Option<String>,
+    pub forge_token: Option<String>,
+    pub forge_cache: Option<bool>,
+    pub forge_cache_dir: Option<PathBuf>,
+    pub forge_concurrency: Option<usize>,
+    pub metadata_max_age_days: Option<u32>,
+    pub severity_map: SeverityMap,
 }
 
 pub fn load_config_file(path: &Path) -> ContractResult<Option<ConfigFile>> {
@@ -55,6 +87,37 @@ pub fn resolve_cli_config(config_file: Option<ConfigFile>) -> CliConfig {
         resolved.strict = config_file.default.strict;
         resolved.check_rules = config_file.check.rules;
         resolved.github_token = config_file.github.token;
+        resolved.forge_kind = config_file.forge.kind;
+        resolved.forge_url = config_file.forge.url;
+        resolved.forge_token = config_file.forge.token;
+        resolved.forge_cache = config_file.forge.cache.or(config_file.github.cache);
+        resolved.forge_cache_dir = config_file.forge.cache_dir.or(config_file.github.cache_dir);
+        resolved.forge_concurrency = config_file
+            .forge
+            .concurrency
+            .or(config_file.github.concurrency);
+        resolved.metadata_max_age_days = config_file.metadata.max_age_days;
+        resolved.severity_map = config_file.severity_map;
     }
     resolved
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_cli_config, ConfigFile};
+    use std::path::PathBuf;
+
+    #[test]
+    fn legacy_github_keys_fall_back_behind_forge_section() {
+        let config: ConfigFile = toml::from_str(
+            "[github]\ncache = false\ncache_dir = \"old\"\nconcurrency = 2\n\n[forge]\nconcurrency = 8\n",
+        )
+        .expect("parse config");
+
+        let resolved = resolve_cli_config(Some(config));
+
+        assert_eq!(resolved.forge_cache, Some(false));
+        assert_eq!(resolved.forge_cache_dir, Some(PathBuf::from("old")));
+        assert_eq!(resolved.forge_concurrency, Some(8));
+    }
+}
@@ -1,18 +1,29 @@
+use crate::{Label, RepositorySettings, SourceLocation};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Contract {
     pub version: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub branch_protection: Option<BranchProtection>,
     #[serde(default)]
     pub required_files: Vec<RequiredFile>,
-    #[serde(default)]
-    pub metadata: Option<serde_yaml::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity_map: SeverityMap,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exceptions: Vec<Exception>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<RepositorySettings>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<Label>,
 }
 
 impl Contract {
@@ -25,6 +36,15 @@ impl Contract {
         if profile.metadata.is_some() {
             merged.metadata = profile.metadata;
         }
+        merged.severity_map.extend(profile.severity_map);
+        merged.exceptions.extend(profile.exceptions);
+        if profile.settings.is_some() {
+            merged.settings = profile.settings;
+        }
+        for label in profile.labels {
+            merged.labels.retain(|existing| existing.name != label.name);
+            merged.labels.push(label);
+        }
         merged
     }
 }
@@ -35,6 +55,109 @@ pub struct BranchProtection {
     pub branches: Vec<String>,
     #[serde(default)]
     pub rules: BranchProtectionRules,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severity: BTreeMap<String, Severity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub branch_severity: Vec<BranchSeverityOverride>,
+    #[serde(skip)]
+    pub locations: BTreeMap<String, SourceLocation>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "RawBranchSeverityOverride")]
+pub struct BranchSeverityOverride {
+    pub branches: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, Severity>,
+    /// `branches` compiled once when the contract is loaded.
+    #[serde(skip)]
+    matcher: GlobSet,
+}
+
+#[derive(Deserialize)]
+struct RawBranchSeverityOverride {
+    branches: Vec<String>,
+    #[serde(default)]
+    severity: Option<Severity>,
+    #[serde(default)]
+    fields: BTreeMap<String, Severity>,
+}
+
+impl TryFrom<RawBranchSeverityOverride> for BranchSeverityOverride {
+    type Error = String;
+
+    fn try_from(raw: RawBranchSeverityOverride) -> Result<Self, Self::Error> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &raw.branches {
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .map_err(|error| format!("Invalid branch glob \"{pattern}\": {error}"))?;
+            builder.add(glob);
+        }
+        Ok(Self {
+            matcher: builder.build().map_err(|error| error.to_string())?,
+            branches: raw.branches,
+            severity: raw.severity,
+            fields: raw.fields,
+        })
+    }
+}
+
+impl BranchSeverityOverride {
+    fn matches(&self, branch: &str) -> bool {
+        self.matcher.is_match(branch)
+    }
+}
+
+impl BranchProtection {
+    pub fn severity_for(&self, branch: &str, path: &str) -> Option<Severity> {
+        if let Some(branch_override) = self
+            .branch_severity
+            .iter()
+            .find(|branch_override| branch_override.matches(branch))
+        {
+            if let Some(severity) = field_severity(&branch_override.fields, path) {
+                return Some(severity);
+            }
+            if branch_override.severity.is_some() {
+                return branch_override.severity;
+            }
+        }
+        field_severity(&self.severity, path)
+    }
+
+    pub fn location_for(&self, path: &str) -> Option<&SourceLocation> {
+        let mut key = if path == "branch_protection" {
+            String::new()
+        } else {
+            format!("rules.{path}")
+        };
+        loop {
+            if let Some(location) = self.locations.get(&key) {
+                return Some(location);
+            }
+            if key.is_empty() {
+                return None;
+            }
+            key = key
+                .rsplit_once('.')
+                .map(|(parent, _)| parent.to_string())
+                .unwrap_or_default();
+        }
+    }
+}
+
+fn field_severity(fields: &BTreeMap<String, Severity>, path: &str) -> Option<Severity> {
+    let mut key = path;
+    loop {
+        if let Some(severity) = fields.get(key) {
+            return Some(*severity);
+        }
+        key = key.rsplit_once('.')?.0;
+    }
 }
 
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
@@ -131,9 +254,88 @@ pub struct RequiredFile {
     pub severity: Severity,
     #[serde(default)]
     pub case_insensitive: bool,
+    #[serde(skip)]
+    pub location: Option<SourceLocation>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct Metadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub maintainers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Tier>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yaml::Value>,
+    #[serde(skip)]
+    pub locations: BTreeMap<String, SourceLocation>,
+}
+
+/// Repository importance tier. Names other than the built-in ones refer to presets under `tiers`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum Tier {
+    Critical,
+    Standard,
+    Experimental,
+    Custom(String),
+}
+
+impl Tier {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Tier::Critical => "critical",
+            Tier::Standard => "standard",
+            Tier::Experimental => "experimental",
+            Tier::Custom(name) => name,
+        }
+    }
+}
+
+impl From<String> for Tier {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "critical" => Tier::Critical,
+            "standard" => Tier::Standard,
+            "experimental" => Tier::Experimental,
+            _ => Tier::Custom(name),
+        }
+    }
+}
+
+impl From<Tier> for String {
+    fn from(tier: Tier) -> Self {
+        tier.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for Tier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Exception {
+    pub rule: String,
+    pub target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub reason: String,
+    pub owner: String,
+    pub expires: String,
+    #[serde(skip)]
+    pub location: Option<SourceLocation>,
+}
+
+pub type SeverityMap = BTreeMap<Severity, Severity>;
+
+#[derive(
+    Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     #[default]
@@ -150,4 +352,8 @@ impl Severity {
             Severity::Info => "info",
         }
     }
+
+    pub fn remap(self, map: &SeverityMap) -> Severity {
+        map.get(&self).copied().unwrap_or(self)
+    }
 }
@@ -0,0 +1,122 @@
+use crate::RequiredFile;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleDescriptor {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub rule: &'static str,
+    pub description: &'static str,
+    pub help: &'static str,
+}
+
+pub const RULE_DESCRIPTORS: &[RuleDescriptor] = &[
+    RuleDescriptor {
+        code: "E001",
+        name: "missing-required-file",
+        rule: "required_files",
+        description: "Missing required file",
+        help: "Create the file or one of its alternatives, or lower the severity in the contract",
+    },
+    RuleDescriptor {
+        code: "E002",
+        name: "no-matching-file",
+        rule: "required_files",
+        description: "No file matches the required pattern",
+        help: "Add a file matching the pattern, or lower the severity in the contract",
+    },
+    RuleDescriptor {
+        code: "E010",
+        name: "branch-protection-missing",
+        rule: "branch_protection",
+        description: "Branch protection is not enabled",
+        help: "Enable branch protection for the branch",
+    },
+    RuleDescriptor {
+        code: "E011",
+        name: "review-count-too-low",
+        rule: "branch_protection",
+        description: "Required approving review count is too low",
+        help: "Raise the required approving review count in the branch protection settings",
+    },
+    RuleDescriptor {
+        code: "E012",
+        name: "status-checks-missing",
+        rule: "branch_protection",
+        description: "Required status checks are missing",
+        help: "Add the missing status checks to the branch protection settings",
+    },
+    RuleDescriptor {
+        code: "W001",
+        name: "branch-protection-drift",
+        rule: "branch_protection",
+        description: "Branch protection setting does not match the contract",
+        help: "Update the branch protection settings or adjust the contract",
+    },
+    RuleDescriptor {
+        code: "E030",
+        name: "maintainer-not-found",
+        rule: "metadata",
+        description: "Maintainer does not exist",
+        help: "Remove the maintainer or replace it with an existing @user or @org/team",
+    },
+    RuleDescriptor {
+        code: "E031",
+        name: "maintainer-without-write-access",
+        rule: "metadata",
+        description: "Maintainer has no write access to the repository",
+        help: "Grant the maintainer write access or remove it from metadata.maintainers",
+    },
+    RuleDescriptor {
+        code: "W030",
+        name: "metadata-outdated",
+        rule: "metadata",
+        description: "Contract metadata has not been updated recently",
+        help: "Review the contract and bump metadata.last_updated",
+    },
+    RuleDescriptor {
+        code: "E020",
+        name: "invalid-contract",
+        rule: "schema",
+        description: "Contract file does not match the schema",
+        help: "Fix the contract so that it matches the JSON Schema (see `repo-contract schema`)",
+    },
+];
+
+pub fn rule_descriptor(code: &str) -> Option<&'static RuleDescriptor> {
+    RULE_DESCRIPTORS
+        .iter()
+        .find(|descriptor| descriptor.code == code)
+}
+
+/// Rule of a required file entry; the severity only affects the reported level.
+pub fn required_file_descriptor(required: &RequiredFile) -> &'static RuleDescriptor {
+    let code = if required.path.is_none() && required.pattern.is_some() {
+        "E002"
+    } else {
+        "E001"
+    };
+    descriptor(code)
+}
+
+pub fn branch_protection_descriptor(path: &str) -> &'static RuleDescriptor {
+    let code = match path {
+        "branch_protection" => "E010",
+        "required_pull_request_reviews.required_approving_review_count" => "E011",
+        "required_status_checks.checks" => "E012",
+        _ => "W001",
+    };
+    descriptor(code)
+}
+
+pub fn metadata_descriptor(path: &str) -> &'static RuleDescriptor {
+    let code = match path {
+        "maintainers.write_access" => "E031",
+        "last_updated" => "W030",
+        _ => "E030",
+    };
+    descriptor(code)
+}
+
+fn descriptor(code: &str) -> &'static RuleDescriptor {
+    rule_descriptor(code).expect("rule descriptor is defined")
+}
@@ -1,12 +1,20 @@
 use crate::required_files::{RequiredFileCheck, Summary};
-use crate::Severity;
+use crate::{Severity, Waiver};
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffType {
+    ValueMismatch,
+    ArrayDiff,
+    MissingFile,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DiffEntry {
     pub rule: String,
     pub path: String,
     #[serde(rename = "type")]
-    pub diff_type: String,
+    pub diff_type: DiffType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub severity: Option<Severity>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -19,6 +27,8 @@ pub struct DiffEntry {
     pub missing: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extra: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiver: Option<Waiver>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -35,21 +45,26 @@ pub fn diff_required_files(checks: &[RequiredFileCheck]) -> DiffReport {
         if check.exists {
             continue;
         }
-        match check.severity {
-            Severity::Error => summary.error += 1,
-            Severity::Warning => summary.warning += 1,
-            Severity::Info => summary.info += 1,
+        if check.waiver.as_ref().is_some_and(|waiver| !waiver.expired) {
+            summary.waived += 1;
+        } else {
+            match check.severity {
+                Severity::Error => summary.error += 1,
+                Severity::Warning => summary.warning += 1,
+                Severity::Info => summary.info += 1,
+            }
         }
         diffs.push(DiffEntry {
             rule: "required_files".to_string(),
             path: check.path.clone(),
-            diff_type: "missing_file".to_string(),
+            diff_type: DiffType::MissingFile,
             severity: Some(check.severity),
             target: None,
             expected: None,
             actual: None,
             missing: None,
             extra: None,
+            waiver: check.waiver.clone(),
         });
     }
 
@@ -0,0 +1,80 @@
+use crate::{ContractError, ContractResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+pub const CONTRACT_FILE_NAMES: &[&str] = &[
+    "contract.yml",
+    "contract.json",
+    "contract.toml",
+    ".github/contract.yml",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl ContractFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => ContractFormat::Json,
+            Some("toml") => ContractFormat::Toml,
+            _ => ContractFormat::Yaml,
+        }
+    }
+
+    pub fn parse<T: DeserializeOwned>(self, content: &str) -> ContractResult<T> {
+        Ok(match self {
+            ContractFormat::Yaml => serde_yaml::from_str(content)?,
+            ContractFormat::Json => serde_json::from_str(content)?,
+            ContractFormat::Toml => toml::from_str(content)?,
+        })
+    }
+
+    pub fn parse_value(self, content: &str) -> ContractResult<serde_json::Value> {
+        match self {
+            ContractFormat::Yaml => {
+                let value: serde_yaml::Value = serde_yaml::from_str(content)?;
+                Ok(serde_json::to_value(value)?)
+            }
+            _ => self.parse(content),
+        }
+    }
+
+    pub fn render<T: Serialize>(self, value: &T) -> ContractResult<String> {
+        Ok(match self {
+            ContractFormat::Yaml => serde_yaml::to_string(value)?,
+            ContractFormat::Json => format!("{}\n", serde_json::to_string_pretty(value)?),
+            ContractFormat::Toml => toml::to_string(value)
+                .map_err(|error| ContractError::InvalidConfig(error.to_string()))?,
+        })
+    }
+}
+
+pub fn discover_contract(start: &Path) -> Option<PathBuf> {
+    let mut prefix = PathBuf::new();
+    for directory in start.ancestors() {
+        for name in CONTRACT_FILE_NAMES {
+            if directory.join(name).is_file() {
+                return Some(prefix.join(name));
+            }
+        }
+        if directory.join(".git").exists() {
+            break;
+        }
+        prefix.push("..");
+    }
+    None
+}
+
+pub fn profile_path_for(base_path: &Path, profile: &str) -> PathBuf {
+    let directory = base_path.parent().unwrap_or_else(|| Path::new("."));
+    let extension = base_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("yml");
+    directory.join(format!("contract.{profile}.{extension}"))
+}
@@ -0,0 +1,102 @@
+use crate::date::{days_since_epoch, today};
+use crate::{
+    BranchProtectionReport, Exception, MetadataReport, RequiredFilesReport, RuleId, Severity,
+};
+use globset::GlobBuilder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Waiver {
+    pub reason: String,
+    pub owner: String,
+    pub expires: String,
+    pub expired: bool,
+}
+
+impl Waiver {
+    pub fn describe(&self) -> String {
+        if self.expired {
+            format!("waiver expired on {} ({})", self.expires, self.owner)
+        } else {
+            format!(
+                "waived until {} by {}: {}",
+                self.expires, self.owner, self.reason
+            )
+        }
+    }
+}
+
+pub fn apply_exceptions(
+    exceptions: &[Exception],
+    branch_reports: &mut [BranchProtectionReport],
+    required_report: Option<&mut RequiredFilesReport>,
+    metadata_report: Option<&mut MetadataReport>,
+) {
+    if exceptions.is_empty() {
+        return;
+    }
+    let today = today();
+    let waiver_for = |rule: RuleId, target: &str, path: Option<&str>| {
+        exceptions
+            .iter()
+            .find(|exception| matches_exception(exception, rule, target, path))
+            .map(|exception| Waiver {
+                reason: exception.reason.clone(),
+                owner: exception.owner.clone(),
+                expires: exception.expires.clone(),
+                expired: days_since_epoch(&exception.expires).is_none_or(|expires| today > expires),
+            })
+    };
+
+    for report in branch_reports {
+        for detail in report.details.iter_mut().filter(|detail| !detail.passed) {
+            detail.waiver =
+                waiver_for(RuleId::BranchProtection, &report.target, Some(&detail.path));
+            if detail.waiver.as_ref().is_some_and(|waiver| waiver.expired) {
+                detail.severity = Severity::Error;
+            }
+        }
+        for check in &mut report.checks {
+            check.waiver = waiver_for(RuleId::BranchProtection, &report.target, Some(&check.path));
+            if check.waiver.as_ref().is_some_and(|waiver| waiver.expired) {
+                check.severity = Severity::Error;
+            }
+        }
+    }
+    if let Some(report) = required_report {
+        for check in report.checks.iter_mut().filter(|check| !check.exists) {
+            check.waiver = waiver_for(RuleId::RequiredFiles, &check.path, None);
+            if check.waiver.as_ref().is_some_and(|waiver| waiver.expired) {
+                check.severity = Severity::Error;
+            }
+        }
+        report.recount();
+    }
+    if let Some(report) = metadata_report {
+        for check in report.checks.iter_mut().filter(|check| !check.passed) {
+            check.waiver = waiver_for(RuleId::Metadata, &check.target, Some(&check.path));
+            if check.waiver.as_ref().is_some_and(|waiver| waiver.expired) {
+                check.severity = Severity::Error;
+            }
+        }
+    }
+}
+
+fn matches_exception(
+    exception: &Exception,
+    rule: RuleId,
+    target: &str,
+    path: Option<&str>,
+) -> bool {
+    if exception.rule != rule.as_str() {
+        return false;
+    }
+    if exception.path.is_some() && exception.path.as_deref() != path {
+        return false;
+    }
+    exception.target == target
+        || GlobBuilder::new(&exception.target)
+            .literal_separator(true)
+            .build()
+            .is_ok_and(|glob| glob.compile_matcher().is_match(target))
+}
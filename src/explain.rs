@@ -0,0 +1,90 @@
+use crate::loader::load_contract_with_origins;
+use crate::{ContractResult, LoadOptions, SourceLocation, Tier};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplainedField {
+    pub path: String,
+    pub value: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<SourceLocation>,
+    /// Tier whose preset supplied the value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier_preset: Option<Tier>,
+}
+
+pub fn explain_contract(config_path: &Path) -> ContractResult<Vec<ExplainedField>> {
+    let (loaded, origins) = load_contract_with_origins(LoadOptions {
+        config_path: config_path.to_path_buf(),
+        include_profile: true,
+    })?;
+
+    let mut leaves = Vec::new();
+    collect_leaves(
+        &serde_json::to_value(&loaded.contract)?,
+        &mut Vec::new(),
+        &mut leaves,
+    );
+
+    Ok(leaves
+        .into_iter()
+        .map(|(segments, value)| {
+            let origin = origins.origin(&pointer_for(&segments));
+            ExplainedField {
+                path: dotted_path(&segments),
+                value,
+                origin: origin.location,
+                tier_preset: origin.tier_preset,
+            }
+        })
+        .collect())
+}
+
+fn collect_leaves(
+    value: &Value,
+    segments: &mut Vec<String>,
+    leaves: &mut Vec<(Vec<String>, Value)>,
+) {
+    match value {
+        Value::Null => {}
+        Value::Object(map) => {
+            for (key, child) in map {
+                segments.push(key.clone());
+                collect_leaves(child, segments, leaves);
+                segments.pop();
+            }
+        }
+        Value::Array(items) if items.iter().any(Value::is_object) => {
+            for (index, child) in items.iter().enumerate() {
+                segments.push(index.to_string());
+                collect_leaves(child, segments, leaves);
+                segments.pop();
+            }
+        }
+        _ => leaves.push((segments.clone(), value.clone())),
+    }
+}
+
+fn pointer_for(segments: &[String]) -> String {
+    segments
+        .iter()
+        .map(|segment| format!("/{segment}"))
+        .collect()
+}
+
+fn dotted_path(segments: &[String]) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        if segment.parse::<usize>().is_ok() {
+            path.push_str(&format!("[{segment}]"));
+        } else {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+    }
+    path
+}
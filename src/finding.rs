@@ -0,0 +1,228 @@
+use crate::baseline::fingerprint;
+use crate::{
+    branch_protection_descriptor, metadata_descriptor, BaselineEntry, BranchProtectionReport,
+    DiffEntry, DiffType, MetadataReport, RequiredFilesReport, Severity, SourceLocation, Summary,
+    Waiver,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const RESULT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleId {
+    RequiredFiles,
+    BranchProtection,
+    Metadata,
+}
+
+impl RuleId {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RuleId::RequiredFiles => "required_files",
+            RuleId::BranchProtection => "branch_protection",
+            RuleId::Metadata => "metadata",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Finding {
+    pub rule: RuleId,
+    pub target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub code: String,
+    pub severity: Severity,
+    pub passed: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    pub expected: Value,
+    pub actual: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiver: Option<Waiver>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub baselined: bool,
+}
+
+impl Finding {
+    pub fn is_waived(&self) -> bool {
+        !self.passed && self.waiver.as_ref().is_some_and(|waiver| !waiver.expired)
+    }
+
+    pub fn fingerprint(&self) -> String {
+        fingerprint(self.rule, &self.target, self.path.as_deref())
+    }
+
+    pub fn diff_type(&self) -> DiffType {
+        match self.rule {
+            RuleId::RequiredFiles => DiffType::MissingFile,
+            RuleId::BranchProtection if self.missing.is_some() || self.extra.is_some() => {
+                DiffType::ArrayDiff
+            }
+            RuleId::BranchProtection | RuleId::Metadata => DiffType::ValueMismatch,
+        }
+    }
+
+    pub fn to_diff_entry(&self) -> DiffEntry {
+        match self.rule {
+            RuleId::RequiredFiles => DiffEntry {
+                rule: self.rule.as_str().to_string(),
+                path: self.target.clone(),
+                diff_type: self.diff_type(),
+                severity: Some(self.severity),
+                target: None,
+                expected: None,
+                actual: None,
+                missing: None,
+                extra: None,
+                waiver: self.waiver.clone(),
+            },
+            RuleId::BranchProtection | RuleId::Metadata => DiffEntry {
+                rule: self.rule.as_str().to_string(),
+                path: self.path.clone().unwrap_or_default(),
+                diff_type: self.diff_type(),
+                severity: None,
+                target: Some(self.target.clone()),
+                expected: Some(self.expected.clone()),
+                actual: Some(self.actual.clone()),
+                missing: self.missing.clone(),
+                extra: self.extra.clone(),
+                waiver: self.waiver.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckResult {
+    pub version: u32,
+    pub valid: bool,
+    pub summary: Summary,
+    pub findings: Vec<Finding>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl CheckResult {
+    pub fn new(findings: Vec<Finding>, strict: bool) -> Self {
+        let mut summary = Summary::default();
+        for finding in findings.iter().filter(|finding| !finding.passed) {
+            if finding.is_waived() {
+                summary.waived += 1;
+                continue;
+            }
+            if finding.baselined {
+                summary.baselined += 1;
+                continue;
+            }
+            match finding.severity {
+                Severity::Error => summary.error += 1,
+                Severity::Warning => summary.warning += 1,
+                Severity::Info => summary.info += 1,
+            }
+        }
+        Self {
+            version: RESULT_VERSION,
+            valid: summary.error == 0 && !(strict && summary.warning > 0),
+            summary,
+            findings,
+            fixed: Vec::new(),
+        }
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|finding| !finding.passed)
+    }
+
+    pub fn without_passed(mut self) -> Self {
+        self.findings.retain(|finding| !finding.passed);
+        self
+    }
+}
+
+pub fn branch_protection_findings(reports: &[BranchProtectionReport]) -> Vec<Finding> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            report.details.iter().map(|detail| Finding {
+                rule: RuleId::BranchProtection,
+                target: report.target.clone(),
+                path: Some(detail.path.clone()),
+                code: branch_protection_descriptor(&detail.path).code.to_string(),
+                severity: detail.severity,
+                passed: detail.passed,
+                message: detail.message.clone(),
+                expected: detail.expected.clone(),
+                actual: detail.actual.clone(),
+                description: None,
+                missing: detail.missing.clone(),
+                extra: detail.extra.clone(),
+                location: detail.location.clone(),
+                waiver: detail.waiver.clone(),
+                baselined: detail.baselined,
+            })
+        })
+        .collect()
+}
+
+pub fn required_file_findings(report: &RequiredFilesReport) -> Vec<Finding> {
+    report
+        .checks
+        .iter()
+        .map(|check| Finding {
+            rule: RuleId::RequiredFiles,
+            target: check.path.clone(),
+            path: None,
+            code: check.code.clone(),
+            severity: check.severity,
+            passed: check.exists,
+            message: if check.exists {
+                String::new()
+            } else {
+                format!("Required file not found: {}", check.path)
+            },
+            expected: Value::Bool(true),
+            actual: Value::Bool(check.exists),
+            description: check.description.clone(),
+            missing: None,
+            extra: None,
+            location: check.location.clone(),
+            waiver: check.waiver.clone(),
+            baselined: check.baselined,
+        })
+        .collect()
+}
+
+pub fn metadata_findings(report: &MetadataReport) -> Vec<Finding> {
+    report
+        .checks
+        .iter()
+        .map(|check| Finding {
+            rule: RuleId::Metadata,
+            target: check.target.clone(),
+            path: Some(check.path.clone()),
+            code: metadata_descriptor(&check.path).code.to_string(),
+            severity: check.severity,
+            passed: check.passed,
+            message: check.message.clone(),
+            expected: Value::String(check.expected.clone()),
+            actual: Value::String(check.actual.clone()),
+            description: None,
+            missing: None,
+            extra: None,
+            location: check.location.clone(),
+            waiver: check.waiver.clone(),
+            baselined: check.baselined,
+        })
+        .collect()
+}
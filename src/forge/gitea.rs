@@ -0,0 +1,229 @@
+use super::http::{encode_path_segment, HttpClient};
+use super::{collect_pages, rule_matches_branch, Forge, ForgeKind, RepositorySettings};
+use crate::{
+    BranchProtectionRules, ContractResult, RequiredPullRequestReviews, RequiredStatusChecks,
+    ResponseCache, StatusCheck,
+};
+use serde::Deserialize;
+
+const PER_PAGE: usize = 50;
+
+pub struct GiteaClient {
+    http: HttpClient,
+}
+
+impl GiteaClient {
+    pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
+        Self {
+            http: HttpClient::new(ForgeKind::Gitea, base_url, token),
+        }
+    }
+
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.http.set_cache(cache);
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.http.set_concurrency(concurrency);
+        self
+    }
+}
+
+impl Forge for GiteaClient {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::Gitea
+    }
+
+    fn list_branches(&self, repo: &str) -> ContractResult<Vec<String>> {
+        let branches: Vec<GiteaBranch> = collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/repos/{repo}/branches?limit={PER_PAGE}&page={page}"
+            ))
+        })?;
+        Ok(branches.into_iter().map(|branch| branch.name).collect())
+    }
+
+    fn get_branch_protection(
+        &self,
+        repo: &str,
+        branch: &str,
+    ) -> ContractResult<Option<BranchProtectionRules>> {
+        Ok(self
+            .get_branch_protections(repo, &[branch.to_string()])?
+            .pop()
+            .flatten())
+    }
+
+    fn get_branch_protections(
+        &self,
+        repo: &str,
+        branches: &[String],
+    ) -> ContractResult<Vec<Option<BranchProtectionRules>>> {
+        let protections: Vec<GiteaBranchProtection> = self
+            .http
+            .get_json(&format!("/repos/{repo}/branch_protections"))?;
+        Ok(branches
+            .iter()
+            .map(|branch| {
+                protections
+                    .iter()
+                    .find(|protection| rule_matches_branch(protection.rule(), branch))
+                    .map(convert_protection_rules)
+            })
+            .collect())
+    }
+
+    fn list_files(&self, repo: &str, reference: Option<&str>) -> ContractResult<Vec<String>> {
+        let reference = match reference {
+            Some(reference) => reference.to_string(),
+            None => self
+                .get_repository_settings(repo)?
+                .default_branch
+                .unwrap_or_else(|| "HEAD".to_string()),
+        };
+        let reference = encode_path_segment(&reference);
+        let mut files = Vec::new();
+        for page in 1.. {
+            let tree: GiteaTree = self.http.get_json(&format!(
+                "/repos/{repo}/git/trees/{reference}?recursive=true&per_page=1000&page={page}"
+            ))?;
+            let empty = tree.tree.is_empty();
+            files.extend(
+                tree.tree
+                    .into_iter()
+                    .filter(|entry| entry.kind == "blob")
+                    .map(|entry| entry.path),
+            );
+            if !tree.truncated || empty {
+                break;
+            }
+        }
+        Ok(files)
+    }
+
+    fn get_repository_settings(&self, repo: &str) -> ContractResult<RepositorySettings> {
+        let repository: GiteaRepository = self.http.get_json(&format!("/repos/{repo}"))?;
+        Ok(RepositorySettings {
+            default_branch: repository.default_branch,
+            visibility: Some(if repository.private {
+                "private".to_string()
+            } else {
+                "public".to_string()
+            }),
+            archived: Some(repository.archived),
+            allow_merge_commit: repository.allow_merge_commits,
+            allow_squash_merge: repository.allow_squash_merge,
+            allow_rebase_merge: repository.allow_rebase,
+            allow_auto_merge: None,
+            delete_branch_on_merge: repository.default_delete_branch_after_merge,
+        })
+    }
+
+    fn concurrency(&self) -> usize {
+        self.http.concurrency()
+    }
+}
+
+fn convert_protection_rules(protection: &GiteaBranchProtection) -> BranchProtectionRules {
+    let required_approvals = protection.required_approvals.clamp(0, u8::MAX.into()) as u8;
+    BranchProtectionRules {
+        required_pull_request_reviews: RequiredPullRequestReviews {
+            enabled: required_approvals > 0,
+            required_approving_review_count: required_approvals,
+            dismiss_stale_reviews: protection.dismiss_stale_approvals,
+            require_code_owner_reviews: false,
+            require_last_push_approval: false,
+        },
+        required_status_checks: RequiredStatusChecks {
+            enabled: protection.enable_status_check,
+            strict: protection.block_on_outdated_branch,
+            checks: protection
+                .status_check_contexts
+                .iter()
+                .map(|context| StatusCheck {
+                    context: context.clone(),
+                    app_id: None,
+                })
+                .collect(),
+        },
+        enforce_admins: protection.apply_to_admins,
+        required_linear_history: false,
+        allow_force_pushes: protection.enable_force_push,
+        allow_deletions: false,
+        required_conversation_resolution: false,
+        required_signatures: protection.require_signed_commits,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaBranch {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaBranchProtection {
+    #[serde(default)]
+    rule_name: String,
+    #[serde(default)]
+    branch_name: String,
+    #[serde(default)]
+    required_approvals: i64,
+    #[serde(default)]
+    dismiss_stale_approvals: bool,
+    #[serde(default)]
+    enable_status_check: bool,
+    #[serde(default)]
+    status_check_contexts: Vec<String>,
+    #[serde(default)]
+    block_on_outdated_branch: bool,
+    #[serde(default)]
+    require_signed_commits: bool,
+    #[serde(default)]
+    enable_force_push: bool,
+    #[serde(default)]
+    apply_to_admins: bool,
+}
+
+impl GiteaBranchProtection {
+    fn rule(&self) -> &str {
+        if self.rule_name.is_empty() {
+            &self.branch_name
+        } else {
+            &self.rule_name
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRepository {
+    #[serde(default)]
+    default_branch: Option<String>,
+    #[serde(default)]
+    private: bool,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    allow_merge_commits: Option<bool>,
+    #[serde(default)]
+    allow_squash_merge: Option<bool>,
+    #[serde(default)]
+    allow_rebase: Option<bool>,
+    #[serde(default)]
+    default_delete_branch_after_merge: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaTree {
+    #[serde(default)]
+    tree: Vec<GiteaTreeEntry>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaTreeEntry {
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
@@ -0,0 +1,553 @@
+use super::http::{encode_path_segment, HttpClient};
+use super::{collect_pages, Forge, ForgeKind, Label, RepositorySettings};
+use crate::{
+    BranchProtectionRules, ContractResult, RequiredPullRequestReviews, RequiredStatusChecks,
+    ResponseCache, StatusCheck,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const PER_PAGE: usize = 100;
+
+pub struct GithubClient {
+    http: HttpClient,
+}
+
+impl GithubClient {
+    pub fn new(token: Option<String>) -> Self {
+        let base_url = ForgeKind::Github
+            .default_base_url()
+            .unwrap_or_default()
+            .to_string();
+        Self::with_base_url(token, base_url)
+    }
+
+    pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
+        Self {
+            http: HttpClient::new(ForgeKind::Github, base_url, token),
+        }
+    }
+
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.http.set_cache(cache);
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.http.set_concurrency(concurrency);
+        self
+    }
+
+    pub(crate) fn list_issue_comments(
+        &self,
+        repo: &str,
+        number: u64,
+    ) -> ContractResult<Vec<IssueComment>> {
+        collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/repos/{repo}/issues/{number}/comments?per_page={PER_PAGE}&page={page}"
+            ))
+        })
+    }
+
+    /// Login of the token's user, or `None` when the token cannot read
+    /// `/user` (GitHub App and Actions installation tokens).
+    pub(crate) fn authenticated_login(&self) -> ContractResult<Option<String>> {
+        let user: Option<CommentAuthor> = self.http.get_feature_json("/user")?;
+        Ok(user.map(|user| user.login))
+    }
+
+    pub(crate) fn create_issue_comment(
+        &self,
+        repo: &str,
+        number: u64,
+        body: &str,
+    ) -> ContractResult<()> {
+        let path = format!("/repos/{repo}/issues/{number}/comments");
+        self.http
+            .send_json("POST", &path, Some(&json!({ "body": body })))?
+            .ok_or_else(|| {
+                self.http
+                    .error(format!("pull request not found: #{number}"))
+            })?;
+        Ok(())
+    }
+
+    pub(crate) fn update_issue_comment(
+        &self,
+        repo: &str,
+        comment_id: u64,
+        body: &str,
+    ) -> ContractResult<()> {
+        let path = format!("/repos/{repo}/issues/comments/{comment_id}");
+        self.http
+            .send_json("PATCH", &path, Some(&json!({ "body": body })))?
+            .ok_or_else(|| self.http.error(format!("comment not found: {comment_id}")))?;
+        Ok(())
+    }
+
+    pub(crate) fn ensure_label(
+        &self,
+        repo: &str,
+        name: &str,
+        color: &str,
+        description: &str,
+    ) -> ContractResult<()> {
+        let path = format!("/repos/{repo}/labels/{}", encode_path_segment(name));
+        let existing: Option<IssueLabel> = self.http.get_optional_json(&path)?;
+        match existing {
+            None => {
+                self.http.send_json(
+                    "POST",
+                    &format!("/repos/{repo}/labels"),
+                    Some(&json!({ "name": name, "color": color, "description": description })),
+                )?;
+            }
+            Some(label) if !label.color.eq_ignore_ascii_case(color) => {
+                self.http
+                    .send_json("PATCH", &path, Some(&json!({ "color": color })))?;
+            }
+            Some(_) => {}
+        }
+        Ok(())
+    }
+
+    pub(crate) fn list_issue_labels(&self, repo: &str, number: u64) -> ContractResult<Vec<String>> {
+        let labels: Vec<IssueLabel> = collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/repos/{repo}/issues/{number}/labels?per_page={PER_PAGE}&page={page}"
+            ))
+        })?;
+        Ok(labels.into_iter().map(|label| label.name).collect())
+    }
+
+    pub(crate) fn add_issue_label(
+        &self,
+        repo: &str,
+        number: u64,
+        name: &str,
+    ) -> ContractResult<()> {
+        let path = format!("/repos/{repo}/issues/{number}/labels");
+        self.http
+            .send_json("POST", &path, Some(&json!({ "labels": [name] })))?
+            .ok_or_else(|| {
+                self.http
+                    .error(format!("pull request not found: #{number}"))
+            })?;
+        Ok(())
+    }
+
+    pub(crate) fn remove_issue_label(
+        &self,
+        repo: &str,
+        number: u64,
+        name: &str,
+    ) -> ContractResult<()> {
+        let path = format!(
+            "/repos/{repo}/issues/{number}/labels/{}",
+            encode_path_segment(name)
+        );
+        self.http.send_json("DELETE", &path, None)?;
+        Ok(())
+    }
+
+    pub(crate) fn user_exists(&self, login: &str) -> ContractResult<bool> {
+        let path = format!("/users/{}", encode_path_segment(login));
+        let user: Option<Value> = self.http.get_optional_json(&path)?;
+        Ok(user.is_some())
+    }
+
+    pub(crate) fn team_exists(&self, org: &str, slug: &str) -> ContractResult<bool> {
+        let path = format!(
+            "/orgs/{}/teams/{}",
+            encode_path_segment(org),
+            encode_path_segment(slug)
+        );
+        let team: Option<Value> = self.http.get_optional_json(&path)?;
+        Ok(team.is_some())
+    }
+
+    pub(crate) fn collaborator_permission(
+        &self,
+        repo: &str,
+        login: &str,
+    ) -> ContractResult<Option<String>> {
+        let path = format!(
+            "/repos/{repo}/collaborators/{}/permission",
+            encode_path_segment(login)
+        );
+        let permission: Option<CollaboratorPermission> = self.http.get_optional_json(&path)?;
+        Ok(permission.map(|permission| permission.permission))
+    }
+
+    pub(crate) fn list_repository_teams(&self, repo: &str) -> ContractResult<Vec<RepositoryTeam>> {
+        collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/repos/{repo}/teams?per_page={PER_PAGE}&page={page}"
+            ))
+        })
+    }
+
+    pub(crate) fn create_check_run(&self, repo: &str, body: &Value) -> ContractResult<u64> {
+        let path = format!("/repos/{repo}/check-runs");
+        let created = self
+            .http
+            .send_json("POST", &path, Some(body))?
+            .ok_or_else(|| self.http.error(format!("repository not found: {repo}")))?;
+        created["id"]
+            .as_u64()
+            .ok_or_else(|| self.http.error("check run response has no id"))
+    }
+
+    pub(crate) fn update_check_run(&self, repo: &str, id: u64, body: &Value) -> ContractResult<()> {
+        let path = format!("/repos/{repo}/check-runs/{id}");
+        self.http
+            .send_json("PATCH", &path, Some(body))?
+            .ok_or_else(|| self.http.error(format!("check run not found: {id}")))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct IssueComment {
+    pub(crate) id: u64,
+    #[serde(default)]
+    pub(crate) body: String,
+    #[serde(default)]
+    pub(crate) user: Option<CommentAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CommentAuthor {
+    pub(crate) login: String,
+    #[serde(default, rename = "type")]
+    pub(crate) kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueLabel {
+    name: String,
+    #[serde(default)]
+    color: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CollaboratorPermission {
+    permission: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct RepositoryTeam {
+    pub(crate) slug: String,
+    #[serde(default)]
+    pub(crate) permission: String,
+}
+
+impl Forge for GithubClient {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::Github
+    }
+
+    fn list_branches(&self, repo: &str) -> ContractResult<Vec<String>> {
+        let branches: Vec<GithubBranch> = collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/repos/{repo}/branches?per_page={PER_PAGE}&page={page}"
+            ))
+        })?;
+        Ok(branches.into_iter().map(|branch| branch.name).collect())
+    }
+
+    fn get_branch_protection(
+        &self,
+        repo: &str,
+        branch: &str,
+    ) -> ContractResult<Option<BranchProtectionRules>> {
+        let path = format!("/repos/{repo}/branches/{branch}/protection");
+        let response: Option<GithubBranchProtection> = self.http.get_optional_json(&path)?;
+        Ok(response.map(convert_protection_rules))
+    }
+
+    fn list_files(&self, repo: &str, reference: Option<&str>) -> ContractResult<Vec<String>> {
+        let reference = match reference {
+            Some(reference) => reference.to_string(),
+            None => default_branch(self.get_repository_settings(repo)?),
+        };
+        let tree: GithubTree = self.http.get_json(&format!(
+            "/repos/{repo}/git/trees/{}?recursive=1",
+            encode_path_segment(&reference)
+        ))?;
+        if tree.truncated {
+            return Err(self
+                .http
+                .error("repository tree is too large to list recursively"));
+        }
+        Ok(tree
+            .tree
+            .into_iter()
+            .filter(|entry| entry.kind == "blob")
+            .map(|entry| entry.path)
+            .collect())
+    }
+
+    fn get_repository_settings(&self, repo: &str) -> ContractResult<RepositorySettings> {
+        self.http.get_json(&format!("/repos/{repo}"))
+    }
+
+    fn list_labels(&self, repo: &str) -> ContractResult<Vec<Label>> {
+        collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/repos/{repo}/labels?per_page={PER_PAGE}&page={page}"
+            ))
+        })
+    }
+
+    fn update_branch_protection(
+        &self,
+        repo: &str,
+        branch: &str,
+        rules: &BranchProtectionRules,
+    ) -> ContractResult<()> {
+        let path = format!("/repos/{repo}/branches/{branch}/protection");
+        self.http
+            .send_json("PUT", &path, Some(&protection_request(rules)))?
+            .ok_or_else(|| self.http.error(format!("branch not found: {branch}")))?;
+        let signatures = format!("{path}/required_signatures");
+        if rules.required_signatures {
+            self.http.send_json("POST", &signatures, None)?;
+        } else {
+            self.http.send_json("DELETE", &signatures, None)?;
+        }
+        Ok(())
+    }
+
+    fn update_repository_settings(
+        &self,
+        repo: &str,
+        settings: &RepositorySettings,
+    ) -> ContractResult<()> {
+        self.http
+            .send_json(
+                "PATCH",
+                &format!("/repos/{repo}"),
+                Some(&set_fields(settings)?),
+            )?
+            .ok_or_else(|| self.http.error(format!("repository not found: {repo}")))?;
+        Ok(())
+    }
+
+    fn create_label(&self, repo: &str, label: &Label) -> ContractResult<()> {
+        self.http
+            .send_json(
+                "POST",
+                &format!("/repos/{repo}/labels"),
+                Some(&label_request(label)?),
+            )?
+            .ok_or_else(|| self.http.error(format!("repository not found: {repo}")))?;
+        Ok(())
+    }
+
+    fn update_label(&self, repo: &str, name: &str, label: &Label) -> ContractResult<()> {
+        let path = format!("/repos/{repo}/labels/{}", encode_path_segment(name));
+        self.http
+            .send_json("PATCH", &path, Some(&label_request(label)?))?
+            .ok_or_else(|| self.http.error(format!("label not found: {name}")))?;
+        Ok(())
+    }
+
+    fn concurrency(&self) -> usize {
+        self.http.concurrency()
+    }
+}
+
+fn default_branch(settings: RepositorySettings) -> String {
+    settings
+        .default_branch
+        .unwrap_or_else(|| "HEAD".to_string())
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubTree {
+    tree: Vec<GithubTreeEntry>,
+    #[serde(default)]
+    truncated: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubTreeEntry {
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubBranch {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubBranchProtection {
+    required_pull_request_reviews: Option<GithubPullRequestReviews>,
+    required_status_checks: Option<GithubStatusChecks>,
+    enforce_admins: Option<GithubEnabled>,
+    required_linear_history: Option<GithubEnabled>,
+    allow_force_pushes: Option<GithubEnabled>,
+    allow_deletions: Option<GithubEnabled>,
+    required_conversation_resolution: Option<GithubEnabled>,
+    required_signatures: Option<GithubEnabled>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubPullRequestReviews {
+    required_approving_review_count: u8,
+    dismiss_stale_reviews: bool,
+    require_code_owner_reviews: bool,
+    require_last_push_approval: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubStatusChecks {
+    strict: bool,
+    #[serde(default)]
+    contexts: Vec<String>,
+    #[serde(default)]
+    checks: Vec<GithubStatusCheck>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubStatusCheck {
+    context: String,
+    #[serde(default)]
+    app_id: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubEnabled {
+    enabled: bool,
+}
+
+fn set_fields<T: serde::Serialize>(value: &T) -> ContractResult<Value> {
+    let mut value = serde_json::to_value(value)?;
+    if let Value::Object(fields) = &mut value {
+        fields.retain(|_, field| !field.is_null());
+    }
+    Ok(value)
+}
+
+fn label_request(label: &Label) -> ContractResult<Value> {
+    let mut request = set_fields(label)?;
+    if let Some(color) = label.normalized_color() {
+        request["color"] = Value::String(color);
+    }
+    Ok(request)
+}
+
+fn protection_request(rules: &BranchProtectionRules) -> Value {
+    let reviews = &rules.required_pull_request_reviews;
+    let status_checks = &rules.required_status_checks;
+    json!({
+        "required_status_checks": status_checks.enabled.then(|| json!({
+            "strict": status_checks.strict,
+            "checks": status_checks
+                .checks
+                .iter()
+                .map(|check| json!({ "context": check.context, "app_id": check.app_id }))
+                .collect::<Vec<_>>(),
+        })),
+        "enforce_admins": rules.enforce_admins,
+        "required_pull_request_reviews": reviews.enabled.then(|| json!({
+            "dismiss_stale_reviews": reviews.dismiss_stale_reviews,
+            "require_code_owner_reviews": reviews.require_code_owner_reviews,
+            "required_approving_review_count": reviews.required_approving_review_count,
+            "require_last_push_approval": reviews.require_last_push_approval,
+        })),
+        "restrictions": Value::Null,
+        "required_linear_history": rules.required_linear_history,
+        "allow_force_pushes": rules.allow_force_pushes,
+        "allow_deletions": rules.allow_deletions,
+        "required_conversation_resolution": rules.required_conversation_resolution,
+    })
+}
+
+fn convert_protection_rules(protection: GithubBranchProtection) -> BranchProtectionRules {
+    BranchProtectionRules {
+        required_pull_request_reviews: convert_pull_request_reviews(
+            protection.required_pull_request_reviews,
+        ),
+        required_status_checks: convert_status_checks(protection.required_status_checks),
+        enforce_admins: protection
+            .enforce_admins
+            .map(|value| value.enabled)
+            .unwrap_or(false),
+        required_linear_history: protection
+            .required_linear_history
+            .map(|value| value.enabled)
+            .unwrap_or(false),
+        allow_force_pushes: protection
+            .allow_force_pushes
+            .map(|value| value.enabled)
+            .unwrap_or(false),
+        allow_deletions: protection
+            .allow_deletions
+            .map(|value| value.enabled)
+            .unwrap_or(false),
+        required_conversation_resolution: protection
+            .required_conversation_resolution
+            .map(|value| value.enabled)
+            .unwrap_or(false),
+        required_signatures: protection
+            .required_signatures
+            .map(|value| value.enabled)
+            .unwrap_or(false),
+    }
+}
+
+fn convert_pull_request_reviews(
+    reviews: Option<GithubPullRequestReviews>,
+) -> RequiredPullRequestReviews {
+    if let Some(reviews) = reviews {
+        RequiredPullRequestReviews {
+            enabled: true,
+            required_approving_review_count: reviews.required_approving_review_count,
+            dismiss_stale_reviews: reviews.dismiss_stale_reviews,
+            require_code_owner_reviews: reviews.require_code_owner_reviews,
+            require_last_push_approval: reviews.require_last_push_approval,
+        }
+    } else {
+        RequiredPullRequestReviews {
+            enabled: false,
+            required_approving_review_count: 0,
+            dismiss_stale_reviews: false,
+            require_code_owner_reviews: false,
+            require_last_push_approval: false,
+        }
+    }
+}
+
+fn convert_status_checks(checks: Option<GithubStatusChecks>) -> RequiredStatusChecks {
+    if let Some(checks) = checks {
+        let mut result = Vec::new();
+        for context in checks.contexts {
+            result.push(StatusCheck {
+                context,
+                app_id: None,
+            });
+        }
+        for check in checks.checks {
+            result.push(StatusCheck {
+                context: check.context,
+                app_id: check.app_id,
+            });
+        }
+        RequiredStatusChecks {
+            enabled: true,
+            strict: checks.strict,
+            checks: result,
+        }
+    } else {
+        RequiredStatusChecks {
+            enabled: false,
+            strict: false,
+            checks: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use super::http::{encode_path_segment, HttpClient};
+use super::{collect_pages, rule_matches_branch, Forge, ForgeKind, RepositorySettings};
+use crate::{
+    BranchProtectionRules, ContractResult, RequiredPullRequestReviews, RequiredStatusChecks,
+    ResponseCache, StatusCheck,
+};
+use serde::Deserialize;
+
+const PER_PAGE: usize = 100;
+
+pub struct GitlabClient {
+    http: HttpClient,
+}
+
+impl GitlabClient {
+    pub fn new(token: Option<String>) -> Self {
+        let base_url = ForgeKind::Gitlab
+            .default_base_url()
+            .unwrap_or_default()
+            .to_string();
+        Self::with_base_url(token, base_url)
+    }
+
+    pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
+        Self {
+            http: HttpClient::new(ForgeKind::Gitlab, base_url, token),
+        }
+    }
+
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.http.set_cache(cache);
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.http.set_concurrency(concurrency);
+        self
+    }
+
+    fn project(&self, repo: &str) -> ContractResult<GitlabProject> {
+        self.http
+            .get_json(&format!("/projects/{}", encode_path_segment(repo)))
+    }
+}
+
+impl Forge for GitlabClient {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::Gitlab
+    }
+
+    fn list_branches(&self, repo: &str) -> ContractResult<Vec<String>> {
+        let project = encode_path_segment(repo);
+        let branches: Vec<GitlabBranch> = collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/projects/{project}/repository/branches?per_page={PER_PAGE}&page={page}"
+            ))
+        })?;
+        Ok(branches.into_iter().map(|branch| branch.name).collect())
+    }
+
+    fn get_branch_protection(
+        &self,
+        repo: &str,
+        branch: &str,
+    ) -> ContractResult<Option<BranchProtectionRules>> {
+        Ok(self
+            .get_branch_protections(repo, &[branch.to_string()])?
+            .pop()
+            .flatten())
+    }
+
+    fn get_branch_protections(
+        &self,
+        repo: &str,
+        branches: &[String],
+    ) -> ContractResult<Vec<Option<BranchProtectionRules>>> {
+        let project_id = encode_path_segment(repo);
+        let protected: Vec<GitlabProtectedBranch> = collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/projects/{project_id}/protected_branches?per_page={PER_PAGE}&page={page}"
+            ))
+        })?;
+        let matched = branches
+            .iter()
+            .map(|branch| {
+                protected
+                    .iter()
+                    .find(|protected| rule_matches_branch(&protected.name, branch))
+            })
+            .collect::<Vec<_>>();
+        if matched.iter().all(Option::is_none) {
+            return Ok(branches.iter().map(|_| None).collect());
+        }
+
+        let project = self.project(repo)?;
+        let approval_rules: Vec<GitlabApprovalRule> = self
+            .http
+            .get_feature_json(&format!("/projects/{project_id}/approval_rules"))?
+            .unwrap_or_default();
+        let approvals: GitlabApprovalSettings = self
+            .http
+            .get_feature_json(&format!("/projects/{project_id}/approvals"))?
+            .unwrap_or_default();
+        let status_checks: Vec<GitlabExternalStatusCheck> = self
+            .http
+            .get_feature_json(&format!("/projects/{project_id}/external_status_checks"))?
+            .unwrap_or_default();
+        let push_rule: GitlabPushRule = self
+            .http
+            .get_feature_json::<Option<GitlabPushRule>>(&format!(
+                "/projects/{project_id}/push_rule"
+            ))?
+            .flatten()
+            .unwrap_or_default();
+        let settings = GitlabProtectionSettings {
+            project: &project,
+            approval_rules: &approval_rules,
+            approvals: &approvals,
+            status_checks: &status_checks,
+            push_rule: &push_rule,
+        };
+
+        Ok(matched
+            .into_iter()
+            .map(|protected| protected.map(|protected| settings.rules_for(protected)))
+            .collect())
+    }
+
+    fn list_files(&self, repo: &str, reference: Option<&str>) -> ContractResult<Vec<String>> {
+        let project = encode_path_segment(repo);
+        let reference = reference
+            .map(|reference| format!("&ref={}", encode_path_segment(reference)))
+            .unwrap_or_default();
+        let entries: Vec<GitlabTreeEntry> = collect_pages(PER_PAGE, |page| {
+            self.http.get_json(&format!(
+                "/projects/{project}/repository/tree?recursive=true&per_page={PER_PAGE}&page={page}{reference}"
+            ))
+        })?;
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.kind == "blob")
+            .map(|entry| entry.path)
+            .collect())
+    }
+
+    fn get_repository_settings(&self, repo: &str) -> ContractResult<RepositorySettings> {
+        let project = self.project(repo)?;
+        let merge_method = project.merge_method.as_deref().unwrap_or("merge");
+        Ok(RepositorySettings {
+            default_branch: project.default_branch,
+            visibility: project.visibility,
+            archived: Some(project.archived),
+            allow_merge_commit: Some(merge_method == "merge"),
+            allow_squash_merge: project
+                .squash_option
+                .as_deref()
+                .map(|option| option != "never"),
+            allow_rebase_merge: Some(merge_method != "merge"),
+            allow_auto_merge: None,
+            delete_branch_on_merge: project.remove_source_branch_after_merge,
+        })
+    }
+
+    fn concurrency(&self) -> usize {
+        self.http.concurrency()
+    }
+}
+
+struct GitlabProtectionSettings<'a> {
+    project: &'a GitlabProject,
+    approval_rules: &'a [GitlabApprovalRule],
+    approvals: &'a GitlabApprovalSettings,
+    status_checks: &'a [GitlabExternalStatusCheck],
+    push_rule: &'a GitlabPushRule,
+}
+
+impl GitlabProtectionSettings<'_> {
+    fn rules_for(&self, protected: &GitlabProtectedBranch) -> BranchProtectionRules {
+        let required_approving_review_count = self
+            .approval_rules
+            .iter()
+            .filter(|rule| rule.applies_to(&protected.name))
+            .map(|rule| rule.approvals_required)
+            .max()
+            .unwrap_or(0)
+            .min(u8::MAX.into()) as u8;
+        let checks = self
+            .status_checks
+            .iter()
+            .filter(|check| check.applies_to(&protected.name))
+            .map(|check| StatusCheck {
+                context: check.name.clone(),
+                app_id: None,
+            })
+            .collect::<Vec<_>>();
+        let merge_method = self.project.merge_method.as_deref().unwrap_or("merge");
+
+        BranchProtectionRules {
+            required_pull_request_reviews: RequiredPullRequestReviews {
+                enabled: required_approving_review_count > 0
+                    || protected.code_owner_approval_required,
+                required_approving_review_count,
+                dismiss_stale_reviews: self.approvals.reset_approvals_on_push,
+                require_code_owner_reviews: protected.code_owner_approval_required,
+                require_last_push_approval: self
+                    .approvals
+                    .merge_requests_disable_committers_approval,
+            },
+            required_status_checks: RequiredStatusChecks {
+                enabled: self.project.only_allow_merge_if_pipeline_succeeds || !checks.is_empty(),
+                strict: merge_method != "merge",
+                checks,
+            },
+            enforce_admins: false,
+            required_linear_history: merge_method == "ff",
+            allow_force_pushes: protected.allow_force_push,
+            allow_deletions: false,
+            required_conversation_resolution: self
+                .project
+                .only_allow_merge_if_all_discussions_are_resolved,
+            required_signatures: self.push_rule.reject_unsigned_commits,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabBranch {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabProtectedBranch {
+    name: String,
+    #[serde(default)]
+    allow_force_push: bool,
+    #[serde(default)]
+    code_owner_approval_required: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabProtectedBranchRef {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabApprovalRule {
+    #[serde(default)]
+    approvals_required: u32,
+    #[serde(default)]
+    applies_to_all_protected_branches: bool,
+    #[serde(default)]
+    protected_branches: Vec<GitlabProtectedBranchRef>,
+}
+
+impl GitlabApprovalRule {
+    fn applies_to(&self, protected_name: &str) -> bool {
+        self.applies_to_all_protected_branches
+            || self.protected_branches.is_empty()
+            || self
+                .protected_branches
+                .iter()
+                .any(|branch| branch.name == protected_name)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GitlabApprovalSettings {
+    #[serde(default)]
+    reset_approvals_on_push: bool,
+    #[serde(default)]
+    merge_requests_disable_committers_approval: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabExternalStatusCheck {
+    name: String,
+    #[serde(default)]
+    protected_branches: Vec<GitlabProtectedBranchRef>,
+}
+
+impl GitlabExternalStatusCheck {
+    fn applies_to(&self, protected_name: &str) -> bool {
+        self.protected_branches.is_empty()
+            || self
+                .protected_branches
+                .iter()
+                .any(|branch| branch.name == protected_name)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GitlabPushRule {
+    #[serde(default)]
+    reject_unsigned_commits: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabProject {
+    #[serde(default)]
+    default_branch: Option<String>,
+    #[serde(default)]
+    visibility: Option<String>,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    merge_method: Option<String>,
+    #[serde(default)]
+    squash_option: Option<String>,
+    #[serde(default)]
+    remove_source_branch_after_merge: Option<bool>,
+    #[serde(default)]
+    only_allow_merge_if_pipeline_succeeds: bool,
+    #[serde(default)]
+    only_allow_merge_if_all_discussions_are_resolved: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabTreeEntry {
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
@@ -0,0 +1,305 @@
+use crate::cache::{token_identity, CachedResponse};
+use crate::{ContractError, ContractResult, ForgeKind, ResponseCache};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+const MAX_RATE_LIMIT_RETRIES: usize = 3;
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+pub(crate) struct HttpClient {
+    kind: ForgeKind,
+    base_url: String,
+    token: Option<String>,
+    cache: Option<ResponseCache>,
+    concurrency: usize,
+    agent: ureq::Agent,
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl HttpClient {
+    pub(crate) fn new(kind: ForgeKind, base_url: String, token: Option<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .new_agent();
+        Self {
+            kind,
+            base_url,
+            token,
+            cache: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            agent,
+            paused_until: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn set_cache(&mut self, cache: ResponseCache) {
+        self.cache = Some(cache);
+    }
+
+    pub(crate) fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency.max(1);
+    }
+
+    pub(crate) fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    pub(crate) fn error(&self, message: impl Into<String>) -> ContractError {
+        match self.kind {
+            ForgeKind::Github => ContractError::GitHubApi(message.into()),
+            kind => ContractError::ForgeApi(format!("{}: {}", kind.as_str(), message.into())),
+        }
+    }
+
+    pub(crate) fn get_json<T: DeserializeOwned>(&self, path: &str) -> ContractResult<T> {
+        self.get_optional_json(path)?
+            .ok_or_else(|| self.error("API returned 404"))
+    }
+
+    pub(crate) fn get_optional_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> ContractResult<Option<T>> {
+        self.get(path, false)
+    }
+
+    pub(crate) fn get_feature_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> ContractResult<Option<T>> {
+        self.get(path, true)
+    }
+
+    pub(crate) fn send_json(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&Value>,
+    ) -> ContractResult<Option<Value>> {
+        let url = self.url(path);
+        let body = body.map(serde_json::to_string).transpose()?;
+        let mut response = self.call(method, &url, body, None)?;
+        match response.status().as_u16() {
+            404 => return Ok(None),
+            status if !(200..300).contains(&status) => {
+                let message = response.body_mut().read_to_string().unwrap_or_default();
+                return Err(self.error(format!("{method} {path}: status code {status} {message}")));
+            }
+            _ => {}
+        }
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|error| self.error(error.to_string()))?;
+        if body.trim().is_empty() {
+            return Ok(Some(Value::Null));
+        }
+        let parsed = serde_json::from_str(&body).map_err(|error| self.error(error.to_string()))?;
+        Ok(Some(parsed))
+    }
+
+    fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        forbidden_as_missing: bool,
+    ) -> ContractResult<Option<T>> {
+        let url = self.url(path);
+        let identity = token_identity(self.token.as_deref());
+        let cached = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.load(&url, &identity));
+        let etag = cached.as_ref().map(|cached| cached.etag.as_str());
+        let mut response = self.call("GET", &url, None, etag)?;
+        match response.status().as_u16() {
+            404 => return Ok(None),
+            // A rate-limited 403 left after the retries is an error, not a missing feature.
+            403 | 429 if is_rate_limited(&response) => {
+                return Err(self.error("rate limit exceeded, retries exhausted"));
+            }
+            403 if forbidden_as_missing => return Ok(None),
+            304 => {
+                return match cached {
+                    Some(cached) => Ok(Some(serde_json::from_str(&cached.body)?)),
+                    None => Err(self.error("unexpected 304 without cached response")),
+                };
+            }
+            status if !(200..300).contains(&status) => {
+                return Err(self.error(format!("status code {status}")));
+            }
+            _ => {}
+        }
+        let etag = header_value(&response, "ETag").map(str::to_string);
+        let body = response
+            .body_mut()
+            .read_to_string()
+            .map_err(|error| self.error(error.to_string()))?;
+        let parsed = serde_json::from_str(&body).map_err(|error| self.error(error.to_string()))?;
+        if let (Some(cache), Some(etag)) = (&self.cache, etag) {
+            // The response is already in hand; a cache that cannot be written
+            // only costs the next revalidation.
+            let stored = cache.store(&CachedResponse {
+                url,
+                identity,
+                etag,
+                body,
+            });
+            if let Err(error) = stored {
+                eprintln!(
+                    "warning: failed to write API cache in {}: {error}",
+                    cache.directory().display()
+                );
+            }
+        }
+        Ok(Some(parsed))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    fn call(
+        &self,
+        method: &str,
+        url: &str,
+        body: Option<String>,
+        etag: Option<&str>,
+    ) -> ContractResult<ureq::http::Response<ureq::Body>> {
+        let mut attempt = 0;
+        loop {
+            self.wait_for_rate_limit();
+            let mut request = ureq::http::Request::builder()
+                .method(method)
+                .uri(url)
+                .header("User-Agent", "contract")
+                .header("Accept", self.accept());
+            if let Some((name, value)) = self.authorization() {
+                request = request.header(name, value);
+            }
+            if let Some(etag) = etag {
+                request = request.header("If-None-Match", etag);
+            }
+            let response = match &body {
+                Some(body) => request
+                    .header("Content-Type", "application/json")
+                    .body(body.clone())
+                    .map(|request| self.agent.run(request)),
+                None => request.body(()).map(|request| self.agent.run(request)),
+            }
+            .map_err(|error| self.error(error.to_string()))?
+            .map_err(|error| self.error(error.to_string()))?;
+            match rate_limit_delay(&response) {
+                Some(delay) if attempt < MAX_RATE_LIMIT_RETRIES => {
+                    if delay > MAX_RATE_LIMIT_WAIT {
+                        return Err(self.error(format!(
+                            "rate limit exceeded, retry after {}s",
+                            delay.as_secs()
+                        )));
+                    }
+                    self.pause_for(delay);
+                    attempt += 1;
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    fn accept(&self) -> &'static str {
+        match self.kind {
+            ForgeKind::Github => "application/vnd.github+json",
+            ForgeKind::Gitlab | ForgeKind::Gitea => "application/json",
+        }
+    }
+
+    fn authorization(&self) -> Option<(&'static str, String)> {
+        let token = self.token.as_ref()?;
+        Some(match self.kind {
+            ForgeKind::Github => ("Authorization", format!("Bearer {token}")),
+            ForgeKind::Gitlab => ("PRIVATE-TOKEN", token.clone()),
+            ForgeKind::Gitea => ("Authorization", format!("token {token}")),
+        })
+    }
+
+    fn wait_for_rate_limit(&self) {
+        let paused_until = *self
+            .paused_until
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(until) = paused_until {
+            let now = Instant::now();
+            if until > now {
+                std::thread::sleep(until - now);
+            }
+        }
+    }
+
+    fn pause_for(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut paused_until = self
+            .paused_until
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if paused_until.map(|current| current < until).unwrap_or(true) {
+            *paused_until = Some(until);
+        }
+    }
+}
+
+pub(crate) fn encode_path_segment(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn header_value<'a, B>(response: &'a ureq::http::Response<B>, name: &str) -> Option<&'a str> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+}
+
+fn is_rate_limited<B>(response: &ureq::http::Response<B>) -> bool {
+    rate_limit_delay(response).is_some()
+        || ["X-RateLimit", "RateLimit"]
+            .iter()
+            .any(|prefix| header_value(response, &format!("{prefix}-Remaining")) == Some("0"))
+}
+
+fn rate_limit_delay<B>(response: &ureq::http::Response<B>) -> Option<Duration> {
+    let status = response.status().as_u16();
+    if status != 403 && status != 429 {
+        return None;
+    }
+    if let Some(seconds) =
+        header_value(response, "Retry-After").and_then(|value| value.parse::<u64>().ok())
+    {
+        return Some(Duration::from_secs(seconds));
+    }
+    for prefix in ["X-RateLimit", "RateLimit"] {
+        if header_value(response, &format!("{prefix}-Remaining")) != Some("0") {
+            continue;
+        }
+        let reset = header_value(response, &format!("{prefix}-Reset"))
+            .and_then(|value| value.parse::<u64>().ok())?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        return Some(Duration::from_secs(reset.saturating_sub(now).max(1)));
+    }
+    (status == 429).then_some(Duration::from_secs(1))
+}
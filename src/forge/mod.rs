@@ -0,0 +1,259 @@
+mod gitea;
+mod github;
+mod gitlab;
+mod http;
+
+pub use gitea::GiteaClient;
+pub use github::GithubClient;
+pub use gitlab::GitlabClient;
+
+use crate::{BranchProtectionRules, ContractError, ContractResult};
+use globset::GlobBuilder;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+pub trait Forge: Sync {
+    fn kind(&self) -> ForgeKind;
+
+    fn list_branches(&self, repo: &str) -> ContractResult<Vec<String>>;
+
+    fn get_branch_protection(
+        &self,
+        repo: &str,
+        branch: &str,
+    ) -> ContractResult<Option<BranchProtectionRules>>;
+
+    fn get_branch_protections(
+        &self,
+        repo: &str,
+        branches: &[String],
+    ) -> ContractResult<Vec<Option<BranchProtectionRules>>> {
+        map_concurrently(branches, self.concurrency(), |branch| {
+            self.get_branch_protection(repo, branch)
+        })
+    }
+
+    fn list_files(&self, repo: &str, reference: Option<&str>) -> ContractResult<Vec<String>>;
+
+    fn get_repository_settings(&self, repo: &str) -> ContractResult<RepositorySettings>;
+
+    fn list_labels(&self, _repo: &str) -> ContractResult<Vec<Label>> {
+        Err(unsupported(self.kind(), "listing labels"))
+    }
+
+    fn update_branch_protection(
+        &self,
+        _repo: &str,
+        _branch: &str,
+        _rules: &BranchProtectionRules,
+    ) -> ContractResult<()> {
+        Err(unsupported(self.kind(), "updating branch protection"))
+    }
+
+    fn update_repository_settings(
+        &self,
+        _repo: &str,
+        _settings: &RepositorySettings,
+    ) -> ContractResult<()> {
+        Err(unsupported(self.kind(), "updating repository settings"))
+    }
+
+    fn create_label(&self, _repo: &str, _label: &Label) -> ContractResult<()> {
+        Err(unsupported(self.kind(), "creating labels"))
+    }
+
+    fn update_label(&self, _repo: &str, _name: &str, _label: &Label) -> ContractResult<()> {
+        Err(unsupported(self.kind(), "updating labels"))
+    }
+
+    fn concurrency(&self) -> usize {
+        1
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    #[default]
+    Github,
+    Gitlab,
+    #[serde(alias = "forgejo")]
+    Gitea,
+}
+
+impl ForgeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ForgeKind::Github => "github",
+            ForgeKind::Gitlab => "gitlab",
+            ForgeKind::Gitea => "gitea",
+        }
+    }
+
+    pub fn default_base_url(self) -> Option<&'static str> {
+        match self {
+            ForgeKind::Github => Some("https://api.github.com"),
+            ForgeKind::Gitlab => Some("https://gitlab.com/api/v4"),
+            ForgeKind::Gitea => None,
+        }
+    }
+
+    pub fn token_env(self) -> &'static str {
+        match self {
+            ForgeKind::Github => "GITHUB_TOKEN",
+            ForgeKind::Gitlab => "GITLAB_TOKEN",
+            ForgeKind::Gitea => "GITEA_TOKEN",
+        }
+    }
+
+    /// Whether `apply` can write plans back through this forge's API.
+    pub fn supports_apply(self) -> bool {
+        matches!(self, ForgeKind::Github)
+    }
+
+    /// Branch protection fields the forge API cannot report; these are not evaluated.
+    pub fn unsupported_branch_protection_fields(self) -> &'static [&'static str] {
+        match self {
+            ForgeKind::Github => &[],
+            ForgeKind::Gitlab => &["enforce_admins", "allow_deletions"],
+            ForgeKind::Gitea => &[
+                "required_pull_request_reviews.require_code_owner_reviews",
+                "required_pull_request_reviews.require_last_push_approval",
+                "required_linear_history",
+                "allow_deletions",
+                "required_conversation_resolution",
+            ],
+        }
+    }
+}
+
+impl FromStr for ForgeKind {
+    type Err = ContractError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "github" => Ok(ForgeKind::Github),
+            "gitlab" => Ok(ForgeKind::Gitlab),
+            "gitea" | "forgejo" => Ok(ForgeKind::Gitea),
+            other => Err(ContractError::InvalidConfig(format!(
+                "unknown forge: {other}"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RepositorySettings {
+    #[serde(default)]
+    pub default_branch: Option<String>,
+    #[serde(default)]
+    pub visibility: Option<String>,
+    #[serde(default)]
+    pub archived: Option<bool>,
+    #[serde(default)]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(default)]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(default)]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(default)]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(default)]
+    pub delete_branch_on_merge: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Label {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Label {
+    /// Colours compare without a leading `#` and case-insensitively.
+    pub fn normalized_color(&self) -> Option<String> {
+        self.color
+            .as_deref()
+            .map(|color| color.trim_start_matches('#').to_ascii_lowercase())
+    }
+}
+
+pub(crate) fn unsupported(kind: ForgeKind, action: &str) -> ContractError {
+    ContractError::ForgeApi(format!("{}: {action} is not supported", kind.as_str()))
+}
+
+pub(crate) fn map_concurrently<T, R, F>(items: &[T], workers: usize, f: F) -> ContractResult<Vec<R>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> ContractResult<R> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+    std::thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                if failed.load(Ordering::Relaxed) {
+                    break;
+                }
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = f(item);
+                if result.is_err() {
+                    failed.store(true, Ordering::Relaxed);
+                }
+                results.lock().unwrap_or_else(PoisonError::into_inner)[index] = Some(result);
+            });
+        }
+    });
+    let results = results.into_inner().unwrap_or_else(PoisonError::into_inner);
+    let mut collected = Vec::with_capacity(items.len());
+    let mut cancelled = false;
+    for result in results {
+        match result {
+            Some(Ok(value)) => collected.push(value),
+            Some(Err(error)) => return Err(error),
+            None => cancelled = true,
+        }
+    }
+    if cancelled {
+        return Err(ContractError::ForgeApi(
+            "request was cancelled after an earlier failure".to_string(),
+        ));
+    }
+    Ok(collected)
+}
+
+fn collect_pages<T, F>(per_page: usize, mut fetch: F) -> ContractResult<Vec<T>>
+where
+    F: FnMut(usize) -> ContractResult<Vec<T>>,
+{
+    let mut items = Vec::new();
+    for page in 1.. {
+        let batch = fetch(page)?;
+        let done = batch.len() < per_page;
+        items.extend(batch);
+        if done {
+            break;
+        }
+    }
+    Ok(items)
+}
+
+fn rule_matches_branch(rule: &str, branch: &str) -> bool {
+    if rule == branch {
+        return true;
+    }
+    GlobBuilder::new(rule)
+        .literal_separator(false)
+        .build()
+        .map(|glob| glob.compile_matcher().is_match(branch))
+        .unwrap_or(false)
+}
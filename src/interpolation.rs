@@ -0,0 +1,359 @@
+use crate::schema::scalar_field_type;
+use crate::tier::{tier_preset, TierPreset};
+use crate::Tier;
+use serde_yaml::{Mapping, Value};
+use std::collections::BTreeMap;
+
+/// Only variables with this prefix are read from the environment, so a
+/// contract cannot pull secrets such as `GITHUB_TOKEN` into published output.
+const ENV_VARIABLE_PREFIX: &str = "CONTRACT_";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UndefinedVariable {
+    pub(crate) pointer: String,
+    pub(crate) name: String,
+}
+
+/// Tier and variables in effect for a file; a profile inherits the base contract's scope.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Scope {
+    pub(crate) tier: Option<Tier>,
+    pub(crate) variables: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedDocument {
+    pub(crate) document: Value,
+    pub(crate) preset: Option<TierPreset>,
+    pub(crate) scope: Scope,
+    pub(crate) unknown_tier: Option<String>,
+    pub(crate) invalid_preset: Option<(String, String)>,
+    /// Document pointers filled by `when` blocks or by `${VAR}` references to
+    /// collections, mapped to the pointer of the block or variable they came from.
+    pub(crate) overlays: BTreeMap<String, String>,
+    pub(crate) undefined: Vec<UndefinedVariable>,
+}
+
+pub(crate) fn resolve_document(mut document: Value, inherited: &Scope) -> ResolvedDocument {
+    let mut undefined = Vec::new();
+    let Some(mapping) = document.as_mapping_mut() else {
+        return ResolvedDocument {
+            document,
+            preset: None,
+            scope: inherited.clone(),
+            unknown_tier: None,
+            invalid_preset: None,
+            overlays: BTreeMap::new(),
+            undefined,
+        };
+    };
+
+    let mut own_variables = BTreeMap::new();
+    if let Some(Value::Mapping(vars)) = mapping.remove("vars") {
+        for (name, value) in vars {
+            let Some(name) = name.as_str().map(str::to_string) else {
+                continue;
+            };
+            let pointer = format!("/vars/{name}");
+            let value = interpolate(value, &pointer, &BTreeMap::new(), &mut undefined);
+            own_variables.insert(name, value);
+        }
+    }
+    let mut variables = inherited.variables.clone();
+    variables.extend(own_variables.clone());
+    let conditional = mapping.remove("when");
+    let user_tiers = mapping
+        .remove("tiers")
+        .map(|tiers| interpolate(tiers, "/tiers", &variables, &mut undefined));
+
+    let mut overlays = BTreeMap::new();
+    collection_references(&document, "", &own_variables, &mut overlays);
+    let mut document = interpolate(document, "", &variables, &mut undefined);
+    let own_tier = document
+        .get("metadata")
+        .and_then(|metadata| metadata.get("tier"))
+        .and_then(Value::as_str)
+        .map(|tier| Tier::from(tier.to_string()));
+    let mut preset = None;
+    let mut unknown_tier = None;
+    let mut invalid_preset = None;
+    if let Some(tier) = &own_tier {
+        match tier_preset(tier, user_tiers.as_ref().and_then(Value::as_mapping)) {
+            Some(Ok(resolved)) => preset = Some(resolved),
+            Some(Err(message)) => invalid_preset = Some((tier.to_string(), message)),
+            None => unknown_tier = Some(tier.to_string()),
+        }
+    }
+    let tier = own_tier.or_else(|| inherited.tier.clone());
+
+    if let Some(Value::Sequence(blocks)) = conditional {
+        for (index, block) in blocks.into_iter().enumerate() {
+            let pointer = format!("/when/{index}");
+            let mut references = BTreeMap::new();
+            collection_references(&block, &pointer, &own_variables, &mut references);
+            let Value::Mapping(mut block) =
+                interpolate(block, &pointer, &variables, &mut undefined)
+            else {
+                continue;
+            };
+            let matches = match block.remove("tier") {
+                Some(Value::String(expected)) => {
+                    tier.as_ref().map(Tier::as_str) == Some(expected.as_str())
+                }
+                Some(Value::Sequence(expected)) => expected
+                    .iter()
+                    .any(|expected| expected.as_str() == tier.as_ref().map(Tier::as_str)),
+                _ => false,
+            };
+            if matches {
+                let mut merged = BTreeMap::new();
+                merge_into(
+                    &mut document,
+                    Value::Mapping(block),
+                    "",
+                    &pointer,
+                    &mut merged,
+                );
+                for (reference, variable) in references {
+                    let target = merged.iter().find_map(|(target, source)| {
+                        let rest = reference.strip_prefix(source.as_str())?;
+                        (rest.is_empty() || rest.starts_with('/'))
+                            .then(|| format!("{target}{rest}"))
+                    });
+                    if let Some(target) = target {
+                        merged.insert(target, variable);
+                    }
+                }
+                let nested = merged
+                    .keys()
+                    .map(|target| format!("{target}/"))
+                    .collect::<Vec<_>>();
+                overlays.retain(|overlaid: &String, _| {
+                    !nested.iter().any(|prefix| overlaid.starts_with(prefix))
+                });
+                overlays.extend(merged);
+            }
+        }
+    }
+
+    ResolvedDocument {
+        document,
+        preset,
+        scope: Scope { tier, variables },
+        unknown_tier,
+        invalid_preset,
+        overlays,
+        undefined,
+    }
+}
+
+/// Records whole `${VAR}` references that expand to mappings or sequences.
+fn collection_references(
+    value: &Value,
+    pointer: &str,
+    variables: &BTreeMap<String, Value>,
+    references: &mut BTreeMap<String, String>,
+) {
+    match value {
+        Value::String(text) => {
+            if let Some(name) = whole_reference(text) {
+                if matches!(
+                    variables.get(name),
+                    Some(Value::Mapping(_) | Value::Sequence(_))
+                ) {
+                    references.insert(pointer.to_string(), format!("/vars/{name}"));
+                }
+            }
+        }
+        Value::Sequence(items) => {
+            for (index, item) in items.iter().enumerate() {
+                collection_references(item, &format!("{pointer}/{index}"), variables, references);
+            }
+        }
+        Value::Mapping(mapping) => {
+            for (key, item) in mapping {
+                let child = format!("{pointer}/{}", key.as_str().unwrap_or_default());
+                collection_references(item, &child, variables, references);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn merge_into(
+    target: &mut Value,
+    overlay: Value,
+    pointer: &str,
+    source: &str,
+    overlays: &mut BTreeMap<String, String>,
+) {
+    match (target, overlay) {
+        (Value::Mapping(target), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                let name = key.as_str().unwrap_or_default();
+                let child = format!("{pointer}/{name}");
+                let child_source = format!("{source}/{name}");
+                match target.get_mut(&key) {
+                    Some(existing) => merge_into(existing, value, &child, &child_source, overlays),
+                    None => {
+                        overlays.insert(child, child_source);
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Sequence(target), Value::Sequence(overlay)) => {
+            for (index, item) in overlay.into_iter().enumerate() {
+                overlays.insert(
+                    format!("{pointer}/{}", target.len()),
+                    format!("{source}/{index}"),
+                );
+                target.push(item);
+            }
+        }
+        (target, overlay) => {
+            let nested = format!("{pointer}/");
+            overlays.retain(|overlaid, _| !overlaid.starts_with(&nested));
+            overlays.insert(pointer.to_string(), source.to_string());
+            *target = overlay;
+        }
+    }
+}
+
+fn interpolate(
+    value: Value,
+    pointer: &str,
+    variables: &BTreeMap<String, Value>,
+    undefined: &mut Vec<UndefinedVariable>,
+) -> Value {
+    match value {
+        Value::String(text) => interpolate_string(&text, pointer, variables, undefined),
+        Value::Sequence(items) => Value::Sequence(
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    interpolate(item, &format!("{pointer}/{index}"), variables, undefined)
+                })
+                .collect(),
+        ),
+        Value::Mapping(mapping) => Value::Mapping(
+            mapping
+                .into_iter()
+                .map(|(key, item)| {
+                    let child = format!("{pointer}/{}", key.as_str().unwrap_or_default());
+                    let item = interpolate(item, &child, variables, undefined);
+                    (key, item)
+                })
+                .collect::<Mapping>(),
+        ),
+        other => other,
+    }
+}
+
+fn interpolate_string(
+    text: &str,
+    pointer: &str,
+    variables: &BTreeMap<String, Value>,
+    undefined: &mut Vec<UndefinedVariable>,
+) -> Value {
+    let mut lookup = |name: &str| {
+        let value = variables.get(name).cloned().or_else(|| {
+            name.starts_with(ENV_VARIABLE_PREFIX)
+                .then(|| std::env::var(name).ok().map(Value::String))
+                .flatten()
+        });
+        if value.is_none() {
+            undefined.push(UndefinedVariable {
+                pointer: pointer.to_string(),
+                name: name.to_string(),
+            });
+        }
+        value
+    };
+
+    if let Some(name) = whole_reference(text) {
+        return match lookup(name) {
+            Some(Value::String(raw)) => coerce(raw, pointer),
+            Some(value) => value,
+            None => Value::String(text.to_string()),
+        };
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('$') {
+        output.push_str(&rest[..start]);
+        let candidate = &rest[start..];
+        if let Some(escaped) = candidate.strip_prefix("$${") {
+            output.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let reference = candidate
+            .strip_prefix("${")
+            .and_then(|body| body.find('}').map(|end| &body[..end]))
+            .filter(|name| is_variable_name(name));
+        match reference {
+            Some(name) => {
+                match lookup(name) {
+                    Some(value) => output.push_str(&scalar_text(&value)),
+                    None => output.push_str(&candidate[..name.len() + 3]),
+                }
+                rest = &candidate[name.len() + 3..];
+            }
+            None => {
+                output.push('$');
+                rest = &candidate[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    Value::String(output)
+}
+
+/// Converts a string substituted for a whole `${VAR}` scalar when the target
+/// field is a boolean or a number; every other value stays a string.
+fn coerce(raw: String, pointer: &str) -> Value {
+    let mut segments = pointer.split('/').skip(1);
+    let field = match segments.next() {
+        Some("when" | "tiers") => segments
+            .skip(1)
+            .fold(String::new(), |field, segment| format!("{field}/{segment}")),
+        _ => pointer.to_string(),
+    };
+    let coerced = match scalar_field_type(&field) {
+        Some("boolean") => raw.trim().parse::<bool>().ok().map(Value::Bool),
+        Some("integer") => raw.trim().parse::<i64>().ok().map(Value::from),
+        Some("number") => raw.trim().parse::<f64>().ok().map(Value::from),
+        _ => None,
+    };
+    coerced.unwrap_or(Value::String(raw))
+}
+
+fn whole_reference(text: &str) -> Option<&str> {
+    text.strip_prefix("${")
+        .and_then(|body| body.strip_suffix('}'))
+        .filter(|name| is_variable_name(name))
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut characters = name.chars();
+    characters
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && characters.all(|character| character.is_ascii_alphanumeric() || character == '_')
+}
+
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Bool(flag) => flag.to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::Null => String::new(),
+        other => serde_yaml::to_string(other)
+            .unwrap_or_default()
+            .trim_end()
+            .to_string(),
+    }
+}
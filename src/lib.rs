@@ -1,30 +1,96 @@
+mod baseline;
 mod branch_protection;
+mod cache;
+mod check_run;
 mod config;
 mod contract;
+mod date;
+mod diagnostic;
 mod diff;
+mod discovery;
+mod exceptions;
+mod explain;
+mod finding;
+mod forge;
 mod init;
+mod interpolation;
 mod loader;
+mod metadata;
+mod migrate;
+mod origin;
+mod plan;
+mod pull_request;
 mod required_files;
 mod schema;
+mod severity;
+mod snapshot;
+mod source;
+mod tier;
 mod validation;
+mod version;
 
+pub use crate::baseline::{
+    apply_baseline, load_baseline, Baseline, BaselineEntry, BASELINE_VERSION,
+};
 pub use crate::branch_protection::{
     check_branch_protection, diff_branch_protection, summarize_branch_protection,
-    BranchProtectionCheck, BranchProtectionReport, GithubClient,
+    BranchProtectionCheck, BranchProtectionReport,
+};
+pub use crate::cache::ResponseCache;
+pub use crate::check_run::{
+    publish_check_run, AnnotationLevel, CheckAnnotation, CheckConclusion, CheckRun,
+    MAX_ANNOTATIONS_PER_REQUEST,
+};
+pub use crate::config::{
+    load_config_file, resolve_cli_config, CliConfig, ConfigFile, MetadataConfig,
 };
-pub use crate::config::{load_config_file, resolve_cli_config, CliConfig, ConfigFile};
 pub use crate::contract::{
-    BranchProtection, BranchProtectionRules, Contract, RequiredFile, RequiredPullRequestReviews,
-    RequiredStatusChecks, Severity, StatusCheck,
+    BranchProtection, BranchProtectionRules, BranchSeverityOverride, Contract, Exception, Metadata,
+    RequiredFile, RequiredPullRequestReviews, RequiredStatusChecks, Severity, SeverityMap,
+    StatusCheck, Tier,
+};
+pub use crate::diagnostic::{
+    branch_protection_descriptor, metadata_descriptor, required_file_descriptor, rule_descriptor,
+    RuleDescriptor, RULE_DESCRIPTORS,
+};
+pub use crate::diff::{diff_required_files, DiffEntry, DiffReport, DiffType};
+pub use crate::discovery::{
+    discover_contract, profile_path_for, ContractFormat, CONTRACT_FILE_NAMES,
+};
+pub use crate::exceptions::{apply_exceptions, Waiver};
+pub use crate::explain::{explain_contract, ExplainedField};
+pub use crate::finding::{
+    branch_protection_findings, metadata_findings, required_file_findings, CheckResult, Finding,
+    RuleId, RESULT_VERSION,
+};
+pub use crate::forge::{
+    Forge, ForgeKind, GiteaClient, GithubClient, GitlabClient, Label, RepositorySettings,
 };
-pub use crate::diff::{diff_required_files, DiffEntry, DiffReport};
 pub use crate::init::{init_contract_files, InitOptions, InitOutcome};
 pub use crate::loader::{load_contract, LoadOptions, LoadedContract};
+pub use crate::metadata::{check_metadata, MetadataCheck, MetadataOptions, MetadataReport};
+pub use crate::migrate::{migrate_contract, MigrationOutcome};
+pub use crate::plan::{
+    apply_plan, create_plan, load_plan, DesiredState, Plan, PlanAction, PlanScope, PlannedChange,
+    PLAN_VERSION,
+};
+pub use crate::pull_request::{
+    publish_pull_request_report, CommentAction, ContractLabel, PullRequestReport, REPORT_MARKER,
+};
 pub use crate::required_files::{
-    check_required_files, RequiredFileCheck, RequiredFilesReport, Summary,
+    check_remote_required_files, check_required_files, RequiredFileCheck, RequiredFilesReport,
+    Summary,
+};
+pub use crate::schema::{result_schema_json, schema_json, schema_json_for_version};
+pub use crate::severity::{apply_severity_map, apply_severity_policy, compose_severity_maps};
+pub use crate::snapshot::{capture_snapshot, load_snapshot, RepositorySnapshot, SNAPSHOT_VERSION};
+pub use crate::source::{SourceFile, SourceLocation, YamlPathSegment};
+pub use crate::tier::BUILTIN_TIERS;
+pub use crate::validation::{
+    validate_contract_file, validate_merged_contract, validate_profile_file, ValidationIssue,
+    ValidationReport,
 };
-pub use crate::schema::schema_json;
-pub use crate::validation::{validate_contract_file, ValidationIssue, ValidationReport};
+pub use crate::version::{ContractVersion, CURRENT_CONTRACT_VERSION};
 
 pub type ContractResult<T> = Result<T, ContractError>;
 
@@ -44,10 +110,20 @@ pub enum ContractError {
     AlreadyExists(String),
     #[error("Profile file not found: {0}")]
     ProfileNotFound(String),
+    #[error(
+        "Unsupported contract version: {0} (supported: {CURRENT_CONTRACT_VERSION} and older 1.x)"
+    )]
+    UnsupportedVersion(String),
+    #[error("Undefined variables: {0}")]
+    UndefinedVariables(String),
     #[error("Unsupported rule: {0}")]
     UnsupportedRule(String),
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
     #[error("GitHub API error: {0}")]
     GitHubApi(String),
+    #[error("Forge API error: {0}")]
+    ForgeApi(String),
+    #[error("Remote state changed since the plan was created: {0}")]
+    StalePlan(String),
 }
@@ -1,5 +1,13 @@
-use crate::{Contract, ContractError, ContractResult};
+use crate::interpolation::{resolve_document, Scope};
+use crate::origin::{ContractOrigins, PresetFill};
+use crate::source::SourceFile;
+use crate::tier::TierPreset;
+use crate::{
+    profile_path_for, Contract, ContractError, ContractFormat, ContractResult, ContractVersion,
+};
+use serde_yaml::Value;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct LoadOptions {
@@ -15,35 +23,143 @@ pub struct LoadedContract {
 }
 
 pub fn load_contract(options: LoadOptions) -> ContractResult<LoadedContract> {
+    load_contract_with_origins(options).map(|(loaded, _)| loaded)
+}
+
+pub(crate) fn load_contract_with_origins(
+    options: LoadOptions,
+) -> ContractResult<(LoadedContract, ContractOrigins)> {
     let base_path = options.config_path;
-    let base_content = std::fs::read_to_string(&base_path)?;
-    let base: Contract = serde_yaml::from_str(&base_content)?;
-    if options.include_profile {
-        if let Some(profile) = base.profile.clone() {
-            let profile_path = profile_path_for(&base_path, &profile);
-            if !profile_path.exists() {
-                return Err(ContractError::ProfileNotFound(
-                    profile_path.display().to_string(),
-                ));
+    let (mut loaded, profile) = load_files(&base_path, options.include_profile)?;
+    let mut profile_path = None;
+    if let Some((path, profile)) = profile {
+        loaded = loaded.merge_profile(profile);
+        profile_path = Some(path);
+    }
+
+    let LoadedFile {
+        mut contract,
+        document,
+        scope,
+        preset,
+        mut origins,
+    } = loaded;
+    if let (Some((preset, file)), Some(tier)) = (preset, scope.tier.as_ref()) {
+        let filled = preset.apply(&mut contract, &document);
+        origins.apply_preset(PresetFill {
+            tier,
+            source: preset.pointer().map(|pointer| (file, pointer)),
+            filled,
+        });
+    }
+    Ok((
+        LoadedContract {
+            base_path,
+            profile_path,
+            contract,
+        },
+        origins,
+    ))
+}
+
+/// Loads the base contract and its profile as declared, before they are
+/// merged and before the tier preset is applied.
+pub(crate) fn load_declared(
+    config_path: &Path,
+) -> ContractResult<(Contract, Option<(PathBuf, Contract)>)> {
+    let (base, profile) = load_files(config_path, true)?;
+    Ok((
+        base.contract,
+        profile.map(|(path, profile)| (path, profile.contract)),
+    ))
+}
+
+fn load_files(
+    base_path: &Path,
+    include_profile: bool,
+) -> ContractResult<(LoadedFile, Option<(PathBuf, LoadedFile)>)> {
+    let base = load_file(base_path, &Scope::default())?;
+    let Some(profile) = base.contract.profile.as_ref().filter(|_| include_profile) else {
+        return Ok((base, None));
+    };
+    let path = profile_path_for(base_path, profile);
+    if !path.exists() {
+        return Err(ContractError::ProfileNotFound(path.display().to_string()));
+    }
+    let profile = load_file(&path, &base.scope)?;
+    Ok((base, Some((path, profile))))
+}
+
+/// A resolved contract file, or the base merged with its profile, before the
+/// tier preset is applied.
+struct LoadedFile {
+    contract: Contract,
+    document: Value,
+    scope: Scope,
+    /// Preset for the tier in `scope`, with the file that resolved it.
+    preset: Option<(TierPreset, Arc<SourceFile>)>,
+    origins: ContractOrigins,
+}
+
+impl LoadedFile {
+    /// Mirrors [`Contract::merge_profile`] for the contract, its resolved
+    /// document and its origins. A profile that declares its own tier brings
+    /// its own preset; otherwise the base preset carries over.
+    fn merge_profile(self, profile: LoadedFile) -> LoadedFile {
+        let origins =
+            self.origins
+                .merge_profile(&self.contract, &profile.contract, &profile.origins);
+        let mut document = self.document;
+        if let (Some(base), Some(overrides)) =
+            (document.as_mapping_mut(), profile.document.as_mapping())
+        {
+            for section in ["branch_protection", "metadata", "settings"] {
+                if let Some(value) = overrides.get(section) {
+                    base.insert(Value::from(section), value.clone());
+                }
             }
-            let profile_content = std::fs::read_to_string(&profile_path)?;
-            let profile_contract: Contract = serde_yaml::from_str(&profile_content)?;
-            let merged = base.merge_profile(profile_contract);
-            return Ok(LoadedContract {
-                base_path,
-                profile_path: Some(profile_path),
-                contract: merged,
-            });
+        }
+        LoadedFile {
+            contract: self.contract.merge_profile(profile.contract),
+            document,
+            scope: profile.scope,
+            preset: profile.preset.or(self.preset),
+            origins,
         }
     }
-    Ok(LoadedContract {
-        base_path,
-        profile_path: None,
-        contract: base,
-    })
 }
 
-fn profile_path_for(base_path: &Path, profile: &str) -> PathBuf {
-    let directory = base_path.parent().unwrap_or_else(|| Path::new("."));
-    directory.join(format!("contract.{profile}.yml"))
+fn load_file(path: &Path, inherited: &Scope) -> ContractResult<LoadedFile> {
+    let source = Arc::new(SourceFile::load(path)?);
+    let raw: serde_yaml::Value = ContractFormat::from_path(path).parse(source.content())?;
+    let resolved = resolve_document(raw, inherited);
+    if !resolved.undefined.is_empty() {
+        let names = resolved
+            .undefined
+            .iter()
+            .map(|variable| format!("${{{}}} at {}", variable.name, variable.pointer))
+            .collect::<Vec<_>>();
+        return Err(ContractError::UndefinedVariables(names.join(", ")));
+    }
+    if let Some(tier) = resolved.unknown_tier {
+        return Err(ContractError::InvalidConfig(format!(
+            "Unknown tier: {tier}"
+        )));
+    }
+    if let Some((tier, message)) = resolved.invalid_preset {
+        return Err(ContractError::InvalidConfig(format!(
+            "Invalid tier preset {tier}: {message}"
+        )));
+    }
+    let mut contract: Contract = serde_yaml::from_value(resolved.document.clone())?;
+    ContractVersion::parse(&contract.version)?.ensure_supported()?;
+    let origins = ContractOrigins::for_file(source.clone(), &resolved.overlays);
+    origins.annotate(&mut contract, &resolved.document);
+    Ok(LoadedFile {
+        contract,
+        document: resolved.document,
+        scope: resolved.scope,
+        preset: resolved.preset.map(|preset| (preset, source)),
+        origins,
+    })
 }
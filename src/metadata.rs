@@ -0,0 +1,216 @@
+use crate::date::{days_since_epoch, today};
+use crate::{
+    ContractError, ContractResult, GithubClient, Metadata, Severity, SourceLocation, Waiver,
+};
+
+const WRITE_PERMISSIONS: &[&str] = &["admin", "maintain", "write", "push"];
+
+#[derive(Debug, Clone, Default)]
+pub struct MetadataOptions {
+    pub max_age_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MetadataCheck {
+    pub path: String,
+    pub target: String,
+    pub passed: bool,
+    pub severity: Severity,
+    pub expected: String,
+    pub actual: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiver: Option<Waiver>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub baselined: bool,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct MetadataReport {
+    pub checks: Vec<MetadataCheck>,
+}
+
+enum Maintainer<'a> {
+    User(&'a str),
+    Team { org: &'a str, slug: &'a str },
+}
+
+pub fn check_metadata(
+    client: Option<&GithubClient>,
+    repo: &str,
+    metadata: &Metadata,
+    options: &MetadataOptions,
+) -> ContractResult<MetadataReport> {
+    let mut checks = Vec::new();
+    if !metadata.maintainers.is_empty() {
+        let client = client.ok_or_else(|| {
+            ContractError::UnsupportedRule(
+                "metadata: maintainers can only be resolved through the GitHub API".to_string(),
+            )
+        })?;
+        let owner = repo.split('/').next().unwrap_or_default();
+        let mut teams = None;
+        for (index, handle) in metadata.maintainers.iter().enumerate() {
+            let location = metadata
+                .locations
+                .get(&format!("maintainers.{index}"))
+                .or_else(|| metadata.locations.get("maintainers"))
+                .cloned();
+            let check =
+                |path: &str, passed: bool, expected: &str, actual: &str, message: String| {
+                    MetadataCheck {
+                        path: path.to_string(),
+                        target: handle.clone(),
+                        passed,
+                        severity: Severity::Error,
+                        expected: expected.to_string(),
+                        actual: actual.to_string(),
+                        message: if passed { String::new() } else { message },
+                        location: location.clone(),
+                        waiver: None,
+                        baselined: false,
+                    }
+                };
+            let Some(maintainer) = parse_handle(handle) else {
+                checks.push(check(
+                    "maintainers",
+                    false,
+                    "@user or @org/team",
+                    handle,
+                    format!("Invalid maintainer handle: {handle} (use @user or @org/team)"),
+                ));
+                continue;
+            };
+            let (exists, permission) = match maintainer {
+                Maintainer::User(login) => {
+                    let exists = client.user_exists(login)?;
+                    let permission = if exists {
+                        client.collaborator_permission(repo, login)?
+                    } else {
+                        None
+                    };
+                    (exists, permission)
+                }
+                Maintainer::Team { org, slug } => {
+                    let exists = client.team_exists(org, slug)?;
+                    let permission = if exists && org.eq_ignore_ascii_case(owner) {
+                        let teams = match &mut teams {
+                            Some(teams) => teams,
+                            None => teams.insert(client.list_repository_teams(repo)?),
+                        };
+                        teams
+                            .iter()
+                            .find(|team| team.slug.eq_ignore_ascii_case(slug))
+                            .map(|team| team.permission.clone())
+                    } else {
+                        None
+                    };
+                    (exists, permission)
+                }
+            };
+            checks.push(check(
+                "maintainers",
+                exists,
+                "exists",
+                if exists { "exists" } else { "missing" },
+                format!("Maintainer {handle} does not exist"),
+            ));
+            if !exists {
+                continue;
+            }
+            let permission = permission.unwrap_or_else(|| "none".to_string());
+            checks.push(check(
+                "maintainers.write_access",
+                WRITE_PERMISSIONS.contains(&permission.as_str()),
+                "write",
+                &permission,
+                format!(
+                    "Maintainer {handle} has no write access to {repo} (permission: {permission})"
+                ),
+            ));
+        }
+    }
+
+    if let Some(max_age_days) = options.max_age_days {
+        let location = metadata.locations.get("last_updated").cloned();
+        let (passed, actual, message) = match metadata.last_updated.as_deref() {
+            None => (
+                false,
+                "not set".to_string(),
+                "last_updated is not set".to_string(),
+            ),
+            Some(date) => match days_since_epoch(date) {
+                None => (
+                    false,
+                    format!("invalid date {date}"),
+                    format!("last_updated is not a valid date: {date}"),
+                ),
+                Some(days) => {
+                    let age = today() - days;
+                    (
+                        age <= i64::from(max_age_days),
+                        format!("{age} days old"),
+                        format!(
+                            "last_updated ({date}) is {age} days old (max {max_age_days} days)"
+                        ),
+                    )
+                }
+            },
+        };
+        checks.push(MetadataCheck {
+            path: "last_updated".to_string(),
+            // A fixed target keeps exceptions and baselines valid across date edits.
+            target: "metadata".to_string(),
+            passed,
+            severity: Severity::Warning,
+            expected: format!("at most {max_age_days} days old"),
+            actual,
+            message: if passed { String::new() } else { message },
+            location: location.or_else(|| metadata.locations.get("").cloned()),
+            waiver: None,
+            baselined: false,
+        });
+    }
+
+    Ok(MetadataReport { checks })
+}
+
+fn parse_handle(handle: &str) -> Option<Maintainer<'_>> {
+    let name = handle.strip_prefix('@')?;
+    let valid = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "-_.".contains(character))
+    };
+    match name.split_once('/') {
+        Some((org, slug)) if valid(org) && valid(slug) => Some(Maintainer::Team { org, slug }),
+        None if valid(name) => Some(Maintainer::User(name)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_user_and_team_handles() {
+        assert!(matches!(
+            parse_handle("@alice"),
+            Some(Maintainer::User("alice"))
+        ));
+        assert!(matches!(
+            parse_handle("@acme/platform"),
+            Some(Maintainer::Team {
+                org: "acme",
+                slug: "platform"
+            })
+        ));
+        assert!(parse_handle("alice").is_none());
+        assert!(parse_handle("@acme/").is_none());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    ContractError, ContractFormat, ContractResult, ContractVersion, CURRENT_CONTRACT_VERSION,
+};
+use serde_yaml::{Mapping, Value};
+
+#[derive(Debug, Clone)]
+pub struct MigrationOutcome {
+    pub from: ContractVersion,
+    pub to: ContractVersion,
+    pub original: String,
+    pub migrated: String,
+}
+
+impl MigrationOutcome {
+    pub fn changed(&self) -> bool {
+        self.original != self.migrated
+    }
+}
+
+struct Migration {
+    from: ContractVersion,
+    to: ContractVersion,
+    apply: fn(&mut Mapping),
+}
+
+const MIGRATIONS: &[Migration] = &[];
+
+pub fn migrate_contract(content: &str, format: ContractFormat) -> ContractResult<MigrationOutcome> {
+    migrate_with(content, format, MIGRATIONS)
+}
+
+fn migrate_with(
+    content: &str,
+    format: ContractFormat,
+    migrations: &[Migration],
+) -> ContractResult<MigrationOutcome> {
+    let mut value: Value = format.parse(content)?;
+    let mapping = value
+        .as_mapping_mut()
+        .ok_or_else(|| ContractError::InvalidConfig("contract must be a mapping".to_string()))?;
+    let declared = mapping
+        .get("version")
+        .and_then(Value::as_str)
+        .ok_or_else(|| ContractError::InvalidConfig("version is missing".to_string()))?;
+    let from = ContractVersion::parse(declared)?;
+    if from > CURRENT_CONTRACT_VERSION {
+        return Err(ContractError::UnsupportedVersion(from.to_string()));
+    }
+
+    let mut version = from;
+    while version != CURRENT_CONTRACT_VERSION {
+        match migrations
+            .iter()
+            .find(|migration| migration.from == version)
+        {
+            Some(migration) => {
+                (migration.apply)(mapping);
+                version = migration.to;
+            }
+            None if version.major == CURRENT_CONTRACT_VERSION.major => {
+                version = CURRENT_CONTRACT_VERSION;
+            }
+            None => return Err(ContractError::UnsupportedVersion(version.to_string())),
+        }
+    }
+
+    let migrated = if from == CURRENT_CONTRACT_VERSION {
+        content.to_string()
+    } else {
+        mapping.insert(
+            Value::String("version".to_string()),
+            Value::String(CURRENT_CONTRACT_VERSION.to_string()),
+        );
+        format.render(&value)?
+    };
+    Ok(MigrationOutcome {
+        from,
+        to: CURRENT_CONTRACT_VERSION,
+        original: content.to_string(),
+        migrated,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename_files(mapping: &mut Mapping) {
+        if let Some(files) = mapping.remove("files") {
+            mapping.insert(Value::String("required_files".to_string()), files);
+        }
+    }
+
+    const LEGACY: &[Migration] = &[Migration {
+        from: ContractVersion { major: 0, minor: 9 },
+        to: ContractVersion { major: 1, minor: 0 },
+        apply: rename_files,
+    }];
+
+    #[test]
+    fn applies_migrations_up_to_current_version() {
+        let outcome = migrate_with(
+            "version: \"0.9\"\nfiles:\n  - path: README.md\n",
+            ContractFormat::Yaml,
+            LEGACY,
+        )
+        .expect("migrate");
+
+        assert!(outcome.changed());
+        assert_eq!(outcome.from, ContractVersion { major: 0, minor: 9 });
+        assert_eq!(
+            outcome.migrated,
+            "version: '1.0'\nrequired_files:\n- path: README.md\n"
+        );
+    }
+
+    #[test]
+    fn leaves_current_contract_untouched() {
+        let content = "version: \"1.0\" # current\nrequired_files: []\n";
+        let outcome = migrate_with(content, ContractFormat::Yaml, LEGACY).expect("migrate");
+
+        assert!(!outcome.changed());
+        assert_eq!(outcome.migrated, content);
+    }
+
+    #[test]
+    fn rejects_versions_without_migration_path() {
+        assert!(matches!(
+            migrate_with("version: \"0.5\"\n", ContractFormat::Yaml, LEGACY),
+            Err(ContractError::UnsupportedVersion(version)) if version == "0.5"
+        ));
+        assert!(matches!(
+            migrate_with("version: \"2.0\"\n", ContractFormat::Yaml, LEGACY),
+            Err(ContractError::UnsupportedVersion(version)) if version == "2.0"
+        ));
+    }
+}
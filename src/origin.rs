@@ -0,0 +1,261 @@
+use crate::{Contract, SourceFile, SourceLocation, Tier};
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Where the value at a contract pointer came from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct FieldOrigin {
+    pub(crate) location: Option<SourceLocation>,
+    pub(crate) tier_preset: Option<Tier>,
+}
+
+/// Fields a tier preset filled in the effective contract, as returned by the preset apply.
+pub(crate) struct PresetFill<'a> {
+    pub(crate) tier: &'a Tier,
+    /// File and pointer of a user-defined preset under `tiers`.
+    pub(crate) source: Option<(Arc<SourceFile>, &'a str)>,
+    pub(crate) filled: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+struct Origin {
+    source: Option<(Arc<SourceFile>, String)>,
+    tier_preset: Option<Tier>,
+}
+
+impl Origin {
+    fn descend(&self, rest: &str) -> Origin {
+        Origin {
+            source: self
+                .source
+                .as_ref()
+                .map(|(file, pointer)| (file.clone(), format!("{pointer}{rest}"))),
+            tier_preset: self.tier_preset.clone(),
+        }
+    }
+}
+
+/// Origins of the effective contract, recorded while files are resolved,
+/// presets applied and the profile merged.
+///
+/// Each entry covers the subtree under its contract pointer; a lookup uses
+/// the deepest entry and follows the rest of the pointer inside its source.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ContractOrigins {
+    entries: BTreeMap<String, Origin>,
+}
+
+impl ContractOrigins {
+    pub(crate) fn for_file(source: Arc<SourceFile>, overlays: &BTreeMap<String, String>) -> Self {
+        let mut entries = BTreeMap::new();
+        entries.insert(
+            String::new(),
+            Origin {
+                source: Some((source.clone(), String::new())),
+                tier_preset: None,
+            },
+        );
+        for (pointer, block) in overlays {
+            entries.insert(
+                pointer.clone(),
+                Origin {
+                    source: Some((source.clone(), block.clone())),
+                    tier_preset: None,
+                },
+            );
+        }
+        Self { entries }
+    }
+
+    /// Records the fields a tier preset filled as coming from that preset.
+    pub(crate) fn apply_preset(&mut self, preset: PresetFill<'_>) {
+        for (pointer, inner) in preset.filled {
+            let nested = format!("{pointer}/");
+            self.entries
+                .retain(|existing, _| *existing != pointer && !existing.starts_with(&nested));
+            self.entries.insert(
+                pointer,
+                Origin {
+                    source: preset
+                        .source
+                        .as_ref()
+                        .map(|(file, prefix)| (file.clone(), format!("{prefix}{inner}"))),
+                    tier_preset: Some(preset.tier.clone()),
+                },
+            );
+        }
+    }
+
+    pub(crate) fn origin(&self, pointer: &str) -> FieldOrigin {
+        let Some(origin) = self.governing(pointer) else {
+            return FieldOrigin::default();
+        };
+        FieldOrigin {
+            location: origin
+                .source
+                .as_ref()
+                .and_then(|(file, pointer)| file.locate_value(pointer)),
+            tier_preset: origin.tier_preset,
+        }
+    }
+
+    /// Locates `pointer`, or its closest ancestor the file wrote.
+    pub(crate) fn locate(&self, pointer: &str) -> Option<SourceLocation> {
+        let mut prefix = pointer;
+        loop {
+            if let Some(location) = self.origin(prefix).location {
+                return Some(location);
+            }
+            prefix = &prefix[..prefix.rfind('/')?];
+        }
+    }
+
+    /// Stores the source locations of one file's rules on `contract`, whose
+    /// resolved document is `document`.
+    pub(crate) fn annotate(&self, contract: &mut Contract, document: &Value) {
+        for (index, required) in contract.required_files.iter_mut().enumerate() {
+            required.location = self.locate(&format!("/required_files/{index}"));
+        }
+        if let Some(branch_protection) = &mut contract.branch_protection {
+            branch_protection.locations = self.key_locations(document, "branch_protection");
+        }
+        for (index, exception) in contract.exceptions.iter_mut().enumerate() {
+            exception.location = self.locate(&format!("/exceptions/{index}"));
+        }
+        if let Some(metadata) = &mut contract.metadata {
+            metadata.locations = self.key_locations(document, "metadata");
+            for index in 0..metadata.maintainers.len() {
+                if let Some(location) = self.locate(&format!("/metadata/maintainers/{index}")) {
+                    metadata
+                        .locations
+                        .insert(format!("maintainers.{index}"), location);
+                }
+            }
+        }
+    }
+
+    /// Locations of every key under the top-level `root` section, keyed by
+    /// dotted path with `""` for the section itself.
+    fn key_locations(&self, document: &Value, root: &str) -> BTreeMap<String, SourceLocation> {
+        let mut locations = BTreeMap::new();
+        if let Some(node) = document.get(root) {
+            self.collect_keys(node, &format!("/{root}"), "", &mut locations);
+        }
+        locations
+    }
+
+    fn collect_keys(
+        &self,
+        node: &Value,
+        pointer: &str,
+        path: &str,
+        locations: &mut BTreeMap<String, SourceLocation>,
+    ) {
+        if let Some(location) = self.origin(pointer).location {
+            locations.insert(path.to_string(), location);
+        }
+        let Value::Mapping(entries) = node else {
+            return;
+        };
+        for (key, value) in entries {
+            let Some(key) = key.as_str() else {
+                continue;
+            };
+            let nested = if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{path}.{key}")
+            };
+            self.collect_keys(value, &format!("{pointer}/{key}"), &nested, locations);
+        }
+    }
+
+    /// Mirrors [`Contract::merge_profile`] so every merged value keeps the
+    /// origin it had in the base or the profile.
+    pub(crate) fn merge_profile(
+        &self,
+        base: &Contract,
+        profile: &Contract,
+        profile_origins: &ContractOrigins,
+    ) -> ContractOrigins {
+        let mut merged = self.clone();
+        let mut take = |destination: String, origins: &ContractOrigins, source: &str| {
+            merged.transplant(&destination, origins, source);
+        };
+
+        let offset = base.required_files.len();
+        for index in 0..profile.required_files.len() {
+            take(
+                format!("/required_files/{}", offset + index),
+                profile_origins,
+                &format!("/required_files/{index}"),
+            );
+        }
+        for (section, replaced) in [
+            ("/branch_protection", profile.branch_protection.is_some()),
+            ("/metadata", profile.metadata.is_some()),
+            ("/settings", profile.settings.is_some()),
+        ] {
+            if replaced {
+                take(section.to_string(), profile_origins, section);
+            }
+        }
+        for severity in profile.severity_map.keys() {
+            let pointer = format!("/severity_map/{}", severity.as_str());
+            take(pointer.clone(), profile_origins, &pointer);
+        }
+        let offset = base.exceptions.len();
+        for index in 0..profile.exceptions.len() {
+            take(
+                format!("/exceptions/{}", offset + index),
+                profile_origins,
+                &format!("/exceptions/{index}"),
+            );
+        }
+
+        let labels = base
+            .labels
+            .iter()
+            .enumerate()
+            .filter(|(_, label)| !profile.labels.iter().any(|other| other.name == label.name))
+            .map(|(index, _)| (self, index))
+            .chain((0..profile.labels.len()).map(|index| (profile_origins, index)))
+            .collect::<Vec<_>>();
+        for (position, (origins, index)) in labels.into_iter().enumerate() {
+            take(
+                format!("/labels/{position}"),
+                origins,
+                &format!("/labels/{index}"),
+            );
+        }
+        merged
+    }
+
+    fn governing(&self, pointer: &str) -> Option<Origin> {
+        let mut prefix = pointer;
+        loop {
+            if let Some(origin) = self.entries.get(prefix) {
+                return Some(origin.descend(&pointer[prefix.len()..]));
+            }
+            prefix = &prefix[..prefix.rfind('/')?];
+        }
+    }
+
+    /// Replaces the subtree at `destination` with the origins of `source` in `origins`.
+    fn transplant(&mut self, destination: &str, origins: &ContractOrigins, source: &str) {
+        let nested = format!("{destination}/");
+        self.entries
+            .retain(|pointer, _| pointer != destination && !pointer.starts_with(&nested));
+        if let Some(origin) = origins.governing(source) {
+            self.entries.insert(destination.to_string(), origin);
+        }
+        let nested = format!("{source}/");
+        for (pointer, origin) in &origins.entries {
+            if let Some(rest) = pointer.strip_prefix(&nested) {
+                self.entries
+                    .insert(format!("{destination}/{rest}"), origin.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,462 @@
+use crate::branch_protection::{
+    branch_protection_report, evaluate_branch_protection, fetch_branch_protections,
+};
+use crate::cache::hex_digest;
+use crate::forge::unsupported;
+use crate::{
+    diff_branch_protection, BranchProtection, BranchProtectionRules, ContractError, ContractResult,
+    DiffEntry, DiffType, Forge, ForgeKind, Label, RepositorySettings, RequiredPullRequestReviews,
+    RequiredStatusChecks,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::path::Path;
+
+pub const PLAN_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Plan {
+    pub version: u32,
+    #[serde(default)]
+    pub forge: ForgeKind,
+    pub repository: String,
+    /// Rules whose remote state was read and is re-checked by `apply`.
+    #[serde(default)]
+    pub rules: Vec<String>,
+    /// Branch patterns from the contract; `apply` re-resolves them against the live branches.
+    #[serde(default)]
+    pub branches: Vec<String>,
+    pub targets: Vec<String>,
+    pub state_digest: String,
+    pub changes: Vec<PlannedChange>,
+    #[serde(default)]
+    pub manual: Vec<DiffEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedChange {
+    pub rule: String,
+    pub target: String,
+    pub action: PlanAction,
+    pub desired: DesiredState,
+    pub diffs: Vec<DiffEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanAction {
+    Create,
+    Update,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DesiredState {
+    BranchProtection(BranchProtectionRules),
+    Settings(RepositorySettings),
+    Label(Label),
+}
+
+/// What `create_plan` compares against the remote repository.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlanScope<'a> {
+    pub branch_protection: Option<&'a BranchProtection>,
+    pub settings: Option<&'a RepositorySettings>,
+    pub labels: &'a [Label],
+}
+
+impl Plan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[derive(Serialize)]
+struct RemoteState {
+    protections: Vec<(String, Option<BranchProtectionRules>)>,
+    settings: Option<RepositorySettings>,
+    labels: Option<Vec<Label>>,
+}
+
+pub fn create_plan(forge: &dyn Forge, repo: &str, scope: PlanScope<'_>) -> ContractResult<Plan> {
+    if !forge.kind().supports_apply() {
+        return Err(unsupported(forge.kind(), "apply"));
+    }
+    let mut rules = Vec::new();
+    if scope.branch_protection.is_some() {
+        rules.push("branch_protection".to_string());
+    }
+    if scope.settings.is_some() {
+        rules.push("settings".to_string());
+    }
+    if !scope.labels.is_empty() {
+        rules.push("labels".to_string());
+    }
+    let branches = scope
+        .branch_protection
+        .map(|config| config.branches.clone())
+        .unwrap_or_default();
+    let state = fetch_remote_state(forge, repo, &rules, &branches)?;
+
+    let mut changes = Vec::new();
+    if let Some(config) = scope.branch_protection {
+        changes.extend(branch_protection_changes(forge, config, &state.protections));
+    }
+    if let (Some(settings), Some(actual)) = (scope.settings, &state.settings) {
+        changes.extend(settings_change(repo, settings, actual)?);
+    }
+    if let Some(actual) = &state.labels {
+        changes.extend(label_changes(scope.labels, actual));
+    }
+
+    Ok(Plan {
+        version: PLAN_VERSION,
+        forge: forge.kind(),
+        repository: repo.to_string(),
+        targets: state
+            .protections
+            .iter()
+            .map(|(target, _)| target.clone())
+            .collect(),
+        state_digest: state_digest(&state)?,
+        rules,
+        branches,
+        changes,
+        manual: Vec::new(),
+    })
+}
+
+pub fn apply_plan(forge: &dyn Forge, plan: &Plan) -> ContractResult<Vec<PlannedChange>> {
+    if forge.kind() != plan.forge {
+        return Err(ContractError::InvalidConfig(format!(
+            "plan was created for {}, not {}",
+            plan.forge.as_str(),
+            forge.kind().as_str()
+        )));
+    }
+    let state = fetch_remote_state(forge, &plan.repository, &plan.rules, &plan.branches)?;
+    let targets = state
+        .protections
+        .iter()
+        .map(|(target, _)| target.as_str())
+        .collect::<Vec<_>>();
+    if targets != plan.targets || state_digest(&state)? != plan.state_digest {
+        return Err(ContractError::StalePlan(plan.repository.clone()));
+    }
+    let mut applied = Vec::new();
+    for change in &plan.changes {
+        match &change.desired {
+            DesiredState::BranchProtection(rules) => {
+                forge.update_branch_protection(&plan.repository, &change.target, rules)?
+            }
+            DesiredState::Settings(settings) => {
+                forge.update_repository_settings(&plan.repository, settings)?
+            }
+            DesiredState::Label(label) => match change.action {
+                PlanAction::Create => forge.create_label(&plan.repository, label)?,
+                PlanAction::Update => {
+                    forge.update_label(&plan.repository, &change.target, label)?
+                }
+            },
+        }
+        applied.push(change.clone());
+    }
+    Ok(applied)
+}
+
+pub fn load_plan(path: &Path) -> ContractResult<Plan> {
+    let content = std::fs::read_to_string(path)?;
+    let plan: Plan = serde_json::from_str(&content)?;
+    if plan.version != PLAN_VERSION {
+        return Err(ContractError::InvalidConfig(format!(
+            "unsupported plan version: {}",
+            plan.version
+        )));
+    }
+    Ok(plan)
+}
+
+fn fetch_remote_state(
+    forge: &dyn Forge,
+    repo: &str,
+    rules: &[String],
+    branches: &[String],
+) -> ContractResult<RemoteState> {
+    let planned = |rule: &str| rules.iter().any(|planned| planned == rule);
+    let protections = if planned("branch_protection") {
+        fetch_branch_protections(forge, repo, branches)?
+    } else {
+        Vec::new()
+    };
+    let settings = if planned("settings") {
+        Some(forge.get_repository_settings(repo)?)
+    } else {
+        None
+    };
+    let labels = if planned("labels") {
+        let mut labels = forge.list_labels(repo)?;
+        labels.sort_by(|left, right| left.name.cmp(&right.name));
+        Some(labels)
+    } else {
+        None
+    };
+    Ok(RemoteState {
+        protections,
+        settings,
+        labels,
+    })
+}
+
+fn branch_protection_changes(
+    forge: &dyn Forge,
+    config: &BranchProtection,
+    protections: &[(String, Option<BranchProtectionRules>)],
+) -> Vec<PlannedChange> {
+    let mut changes = Vec::new();
+    for (target, protection) in protections {
+        let report =
+            branch_protection_report(target.clone(), protection.as_ref(), config, forge.kind());
+        let diffs = diff_branch_protection(std::slice::from_ref(&report));
+        if diffs.is_empty() {
+            continue;
+        }
+        changes.push(PlannedChange {
+            rule: "branch_protection".to_string(),
+            target: target.clone(),
+            action: if protection.is_some() {
+                PlanAction::Update
+            } else {
+                PlanAction::Create
+            },
+            desired: DesiredState::BranchProtection(desired_protection(
+                &config.rules,
+                protection.as_ref(),
+                forge.kind(),
+            )),
+            diffs,
+        });
+    }
+    changes
+}
+
+/// Starts from the live protection and overlays only the evaluated fields that mismatch,
+/// so settings the contract does not mention are written back unchanged.
+fn desired_protection(
+    rules: &BranchProtectionRules,
+    actual: Option<&BranchProtectionRules>,
+    forge: ForgeKind,
+) -> BranchProtectionRules {
+    let mut desired = actual.cloned().unwrap_or_else(unprotected);
+    let unsupported = forge.unsupported_branch_protection_fields();
+    let mismatched = evaluate_branch_protection(rules, &desired)
+        .into_iter()
+        .filter(|detail| !detail.passed && !unsupported.contains(&detail.path.as_str()))
+        .map(|detail| detail.path)
+        .collect::<Vec<_>>();
+    let reviews = &rules.required_pull_request_reviews;
+    let status_checks = &rules.required_status_checks;
+    for path in mismatched {
+        match path.as_str() {
+            "required_pull_request_reviews.enabled" => {
+                desired.required_pull_request_reviews.enabled = reviews.enabled
+            }
+            "required_pull_request_reviews.required_approving_review_count" => {
+                desired
+                    .required_pull_request_reviews
+                    .required_approving_review_count = reviews.required_approving_review_count
+            }
+            "required_pull_request_reviews.dismiss_stale_reviews" => {
+                desired.required_pull_request_reviews.dismiss_stale_reviews =
+                    reviews.dismiss_stale_reviews
+            }
+            "required_pull_request_reviews.require_code_owner_reviews" => {
+                desired
+                    .required_pull_request_reviews
+                    .require_code_owner_reviews = reviews.require_code_owner_reviews
+            }
+            "required_pull_request_reviews.require_last_push_approval" => {
+                desired
+                    .required_pull_request_reviews
+                    .require_last_push_approval = reviews.require_last_push_approval
+            }
+            "required_status_checks.enabled" => {
+                desired.required_status_checks.enabled = status_checks.enabled
+            }
+            "required_status_checks.strict" => {
+                desired.required_status_checks.strict = status_checks.strict
+            }
+            "required_status_checks.checks" => {
+                desired.required_status_checks.checks = status_checks.checks.clone()
+            }
+            "enforce_admins" => desired.enforce_admins = rules.enforce_admins,
+            "required_linear_history" => {
+                desired.required_linear_history = rules.required_linear_history
+            }
+            "allow_force_pushes" => desired.allow_force_pushes = rules.allow_force_pushes,
+            "allow_deletions" => desired.allow_deletions = rules.allow_deletions,
+            "required_conversation_resolution" => {
+                desired.required_conversation_resolution = rules.required_conversation_resolution
+            }
+            "required_signatures" => desired.required_signatures = rules.required_signatures,
+            _ => {}
+        }
+    }
+    desired
+}
+
+fn unprotected() -> BranchProtectionRules {
+    BranchProtectionRules {
+        required_pull_request_reviews: RequiredPullRequestReviews {
+            enabled: false,
+            required_approving_review_count: 0,
+            dismiss_stale_reviews: false,
+            require_code_owner_reviews: false,
+            require_last_push_approval: false,
+        },
+        required_status_checks: RequiredStatusChecks {
+            enabled: false,
+            strict: false,
+            checks: Vec::new(),
+        },
+        enforce_admins: false,
+        required_linear_history: false,
+        allow_force_pushes: false,
+        allow_deletions: false,
+        required_conversation_resolution: false,
+        required_signatures: false,
+    }
+}
+
+fn settings_change(
+    repo: &str,
+    settings: &RepositorySettings,
+    actual: &RepositorySettings,
+) -> ContractResult<Option<PlannedChange>> {
+    let desired = set_fields(serde_json::to_value(settings)?);
+    let actual = set_fields(serde_json::to_value(actual)?);
+    let mut changed = Map::new();
+    let mut diffs = Vec::new();
+    for (field, value) in desired {
+        let current = actual.get(&field).cloned().unwrap_or(Value::Null);
+        if current == value {
+            continue;
+        }
+        diffs.push(value_diff("settings", &field, repo, value.clone(), current));
+        changed.insert(field, value);
+    }
+    if diffs.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(PlannedChange {
+        rule: "settings".to_string(),
+        target: repo.to_string(),
+        action: PlanAction::Update,
+        desired: DesiredState::Settings(serde_json::from_value(Value::Object(changed))?),
+        diffs,
+    }))
+}
+
+fn label_changes(labels: &[Label], actual: &[Label]) -> Vec<PlannedChange> {
+    let mut changes = Vec::new();
+    for label in labels {
+        let Some(existing) = actual
+            .iter()
+            .find(|existing| existing.name.eq_ignore_ascii_case(&label.name))
+        else {
+            changes.push(PlannedChange {
+                rule: "labels".to_string(),
+                target: label.name.clone(),
+                action: PlanAction::Create,
+                desired: DesiredState::Label(label.clone()),
+                diffs: vec![DiffEntry {
+                    rule: "labels".to_string(),
+                    path: "labels".to_string(),
+                    diff_type: DiffType::ArrayDiff,
+                    severity: None,
+                    target: None,
+                    expected: None,
+                    actual: None,
+                    missing: Some(vec![label.name.clone()]),
+                    extra: None,
+                    waiver: None,
+                }],
+            });
+            continue;
+        };
+        let mut desired = Label {
+            name: label.name.clone(),
+            color: None,
+            description: None,
+        };
+        let mut diffs = Vec::new();
+        if existing.name != label.name {
+            diffs.push(value_diff(
+                "labels",
+                "name",
+                &existing.name,
+                Value::String(label.name.clone()),
+                Value::String(existing.name.clone()),
+            ));
+        }
+        if label.color.is_some() && label.normalized_color() != existing.normalized_color() {
+            desired.color = label.color.clone();
+            diffs.push(value_diff(
+                "labels",
+                "color",
+                &existing.name,
+                Value::from(label.normalized_color()),
+                Value::from(existing.normalized_color()),
+            ));
+        }
+        if label.description.is_some() && label.description != existing.description {
+            desired.description = label.description.clone();
+            diffs.push(value_diff(
+                "labels",
+                "description",
+                &existing.name,
+                Value::from(label.description.clone()),
+                Value::from(existing.description.clone()),
+            ));
+        }
+        if diffs.is_empty() {
+            continue;
+        }
+        changes.push(PlannedChange {
+            rule: "labels".to_string(),
+            target: existing.name.clone(),
+            action: PlanAction::Update,
+            desired: DesiredState::Label(desired),
+            diffs,
+        });
+    }
+    changes
+}
+
+fn value_diff(rule: &str, path: &str, target: &str, expected: Value, actual: Value) -> DiffEntry {
+    DiffEntry {
+        rule: rule.to_string(),
+        path: path.to_string(),
+        diff_type: DiffType::ValueMismatch,
+        severity: None,
+        target: Some(target.to_string()),
+        expected: Some(expected),
+        actual: Some(actual),
+        missing: None,
+        extra: None,
+        waiver: None,
+    }
+}
+
+fn set_fields(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(fields) => fields
+            .into_iter()
+            .filter(|(_, field)| !field.is_null())
+            .collect(),
+        _ => Map::new(),
+    }
+}
+
+fn state_digest(state: &RemoteState) -> ContractResult<String> {
+    Ok(hex_digest(serde_json::to_string(state)?.as_bytes()))
+}
@@ -0,0 +1,131 @@
+use crate::{ContractResult, GithubClient, Summary};
+use serde::Serialize;
+
+pub const REPORT_MARKER: &str = "<!-- repo-contract:report -->";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContractLabel {
+    Violation,
+    Warning,
+    Valid,
+}
+
+impl ContractLabel {
+    pub const ALL: [ContractLabel; 3] = [
+        ContractLabel::Violation,
+        ContractLabel::Warning,
+        ContractLabel::Valid,
+    ];
+
+    pub fn from_summary(summary: &Summary) -> Self {
+        if summary.error > 0 {
+            ContractLabel::Violation
+        } else if summary.warning > 0 {
+            ContractLabel::Warning
+        } else {
+            ContractLabel::Valid
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ContractLabel::Violation => "contract:violation",
+            ContractLabel::Warning => "contract:warning",
+            ContractLabel::Valid => "contract:valid",
+        }
+    }
+
+    pub fn color(self) -> &'static str {
+        match self {
+            ContractLabel::Violation => "d73a49",
+            ContractLabel::Warning => "f9c513",
+            ContractLabel::Valid => "28a745",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            ContractLabel::Violation => "Repository contract has errors",
+            ContractLabel::Warning => "Repository contract has warnings",
+            ContractLabel::Valid => "Repository contract is satisfied",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentAction {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PullRequestReport {
+    pub comment: CommentAction,
+    pub label: ContractLabel,
+    pub removed_labels: Vec<String>,
+}
+
+pub fn publish_pull_request_report(
+    client: &GithubClient,
+    repo: &str,
+    number: u64,
+    body: &str,
+    summary: &Summary,
+) -> ContractResult<PullRequestReport> {
+    // Only our own comments are reused: anyone can paste the marker. Tokens
+    // that cannot read `/user` act as a bot, so bot comments are ours then.
+    let login = client.authenticated_login()?;
+    let existing = client
+        .list_issue_comments(repo, number)?
+        .into_iter()
+        .filter(|comment| match (&login, &comment.user) {
+            (Some(login), Some(author)) => author.login.eq_ignore_ascii_case(login),
+            (None, Some(author)) => author.kind == "Bot",
+            (_, None) => false,
+        })
+        .find(|comment| comment.body.contains(REPORT_MARKER));
+    let comment = match existing {
+        Some(existing) if existing.body == body => CommentAction::Unchanged,
+        Some(existing) => {
+            client.update_issue_comment(repo, existing.id, body)?;
+            CommentAction::Updated
+        }
+        None => {
+            client.create_issue_comment(repo, number, body)?;
+            CommentAction::Created
+        }
+    };
+
+    let label = ContractLabel::from_summary(summary);
+    for candidate in ContractLabel::ALL {
+        client.ensure_label(
+            repo,
+            candidate.name(),
+            candidate.color(),
+            candidate.description(),
+        )?;
+    }
+    let current = client.list_issue_labels(repo, number)?;
+    let mut removed_labels = Vec::new();
+    for stale in ContractLabel::ALL
+        .into_iter()
+        .filter(|candidate| *candidate != label)
+    {
+        if current.iter().any(|name| name == stale.name()) {
+            client.remove_issue_label(repo, number, stale.name())?;
+            removed_labels.push(stale.name().to_string());
+        }
+    }
+    if !current.iter().any(|name| name == label.name()) {
+        client.add_issue_label(repo, number, label.name())?;
+    }
+
+    Ok(PullRequestReport {
+        comment,
+        label,
+        removed_labels,
+    })
+}
@@ -1,4 +1,7 @@
-use crate::{ContractError, ContractResult, RequiredFile, Severity};
+use crate::{
+    required_file_descriptor, ContractError, ContractResult, Forge, RequiredFile, Severity,
+    SourceLocation, Waiver,
+};
 use globset::{GlobBuilder, GlobSetBuilder};
 use regex::RegexBuilder;
 use std::collections::HashSet;
@@ -8,17 +11,33 @@ use walkdir::WalkDir;
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct RequiredFileCheck {
     pub path: String,
+    /// Rule code of the entry, e.g. `E001`.
+    pub code: String,
     pub exists: bool,
     pub severity: Severity,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiver: Option<Waiver>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub baselined: bool,
 }
 
-#[derive(Debug, Clone, serde::Serialize, Default)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, PartialEq, Eq)]
 pub struct Summary {
     pub error: usize,
     pub warning: usize,
     pub info: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub waived: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub baselined: usize,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -27,11 +46,55 @@ pub struct RequiredFilesReport {
     pub summary: Summary,
 }
 
+impl RequiredFilesReport {
+    pub(crate) fn recount(&mut self) {
+        let mut summary = Summary::default();
+        for check in self.checks.iter().filter(|check| !check.exists) {
+            if check.waiver.as_ref().is_some_and(|waiver| !waiver.expired) {
+                summary.waived += 1;
+                continue;
+            }
+            if check.baselined {
+                summary.baselined += 1;
+                continue;
+            }
+            match check.severity {
+                Severity::Error => summary.error += 1,
+                Severity::Warning => summary.warning += 1,
+                Severity::Info => summary.info += 1,
+            }
+        }
+        self.summary = summary;
+    }
+}
+
+enum FileSource<'a> {
+    Local(&'a Path),
+    Listed,
+}
+
 pub fn check_required_files(
     root: &Path,
     required_files: &[RequiredFile],
 ) -> ContractResult<RequiredFilesReport> {
     let files = list_files(root)?;
+    evaluate_required_files(FileSource::Local(root), files, required_files)
+}
+
+pub fn check_remote_required_files(
+    forge: &dyn Forge,
+    repo: &str,
+    required_files: &[RequiredFile],
+) -> ContractResult<RequiredFilesReport> {
+    let files = forge.list_files(repo, None)?;
+    evaluate_required_files(FileSource::Listed, files, required_files)
+}
+
+fn evaluate_required_files(
+    source: FileSource<'_>,
+    files: Vec<String>,
+    required_files: &[RequiredFile],
+) -> ContractResult<RequiredFilesReport> {
     let files_lowercase = files
         .iter()
         .map(|path| path.to_lowercase())
@@ -40,7 +103,7 @@ pub fn check_required_files(
     let mut summary = Summary::default();
 
     for required in required_files {
-        let check = evaluate_required_file(required, root, &files, &files_lowercase)?;
+        let check = evaluate_required_file(required, &source, &files, &files_lowercase)?;
         if !check.exists {
             match check.severity {
                 Severity::Error => summary.error += 1,
@@ -56,7 +119,7 @@ pub fn check_required_files(
 
 fn evaluate_required_file(
     required: &RequiredFile,
-    root: &Path,
+    source: &FileSource<'_>,
     files: &[String],
     files_lowercase: &HashSet<String>,
 ) -> ContractResult<RequiredFileCheck> {
@@ -66,7 +129,7 @@ fn evaluate_required_file(
         let exists = candidates.clone().any(|candidate| {
             path_exists(
                 candidate,
-                root,
+                source,
                 files,
                 files_lowercase,
                 required.case_insensitive,
@@ -84,15 +147,19 @@ fn evaluate_required_file(
 
     Ok(RequiredFileCheck {
         path: label,
+        code: required_file_descriptor(required).code.to_string(),
         exists,
         severity: required.severity,
         description: required.description.clone(),
+        location: required.location.clone(),
+        waiver: None,
+        baselined: false,
     })
 }
 
 fn path_exists(
     candidate: &str,
-    root: &Path,
+    source: &FileSource<'_>,
     files: &[String],
     files_lowercase: &HashSet<String>,
     case_insensitive: bool,
@@ -105,7 +172,15 @@ fn path_exists(
         let target = normalized.to_lowercase();
         return files_lowercase.contains(&target);
     }
-    root.join(candidate).exists()
+    match source {
+        FileSource::Local(root) => root.join(candidate).exists(),
+        FileSource::Listed => {
+            let directory = format!("{}/", normalized.trim_end_matches('/'));
+            files
+                .iter()
+                .any(|file| file == &normalized || file.starts_with(&directory))
+        }
+    }
 }
 
 fn looks_like_glob(candidate: &str) -> bool {
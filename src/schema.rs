@@ -1,3 +1,55 @@
+use crate::{ContractError, ContractResult, ContractVersion};
+use serde_json::Value;
+use std::sync::OnceLock;
+
+const CONTRACT_SCHEMA_V1: &str = include_str!("../schemas/contract.v1.schema.json");
+
 pub fn schema_json() -> &'static str {
-    include_str!("../schemas/contract.v1.schema.json")
+    CONTRACT_SCHEMA_V1
+}
+
+pub fn schema_json_for_version(version: &str) -> ContractResult<&'static str> {
+    let version = ContractVersion::parse(version)?.ensure_supported()?;
+    match version.major {
+        1 => Ok(CONTRACT_SCHEMA_V1),
+        _ => Err(ContractError::UnsupportedVersion(version.to_string())),
+    }
+}
+
+pub fn result_schema_json() -> &'static str {
+    include_str!("../schemas/result.v1.schema.json")
+}
+
+/// Scalar type a contract field must have when its schema allows only a
+/// boolean or a number; `None` for strings, mixed types and unknown paths.
+pub(crate) fn scalar_field_type(pointer: &str) -> Option<&'static str> {
+    static SCHEMA: OnceLock<Option<Value>> = OnceLock::new();
+    let root = SCHEMA
+        .get_or_init(|| serde_json::from_str(CONTRACT_SCHEMA_V1).ok())
+        .as_ref()?;
+    let resolve = |node: &'static Value| match node.get("$ref").and_then(Value::as_str) {
+        Some(reference) => root.pointer(reference.strip_prefix('#')?),
+        None => Some(node),
+    };
+    let mut node = resolve(root)?;
+    for segment in pointer.split('/').filter(|segment| !segment.is_empty()) {
+        let child = node
+            .get("properties")
+            .and_then(|properties| properties.get(segment))
+            .or_else(|| {
+                segment
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|_| node.get("items"))
+            })
+            .or_else(|| {
+                node.get("additionalProperties")
+                    .filter(|child| child.is_object())
+            })?;
+        node = resolve(child)?;
+    }
+    match node.get("type")?.as_str()? {
+        kind @ ("boolean" | "integer" | "number") => Some(kind),
+        _ => None,
+    }
 }
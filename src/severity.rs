@@ -0,0 +1,63 @@
+use crate::{
+    apply_exceptions, BranchProtectionReport, Exception, MetadataReport, RequiredFilesReport,
+    SeverityMap,
+};
+
+/// Composes the contract map with the global (`.contract.toml`) map into a
+/// single lookup: each severity is remapped once, and the global entry wins
+/// when both maps name the same severity. Mappings never chain.
+pub fn compose_severity_maps(contract: &SeverityMap, global: &SeverityMap) -> SeverityMap {
+    let mut composed = contract.clone();
+    composed.extend(global.iter().map(|(from, to)| (*from, *to)));
+    composed
+}
+
+/// Finalizes report severities in a fixed order: the composed severity map
+/// first, then exceptions, so the `error` of an expired waiver is never
+/// remapped afterwards.
+pub fn apply_severity_policy(
+    contract_map: &SeverityMap,
+    global_map: &SeverityMap,
+    exceptions: &[Exception],
+    branch_reports: &mut [BranchProtectionReport],
+    mut required_report: Option<&mut RequiredFilesReport>,
+    mut metadata_report: Option<&mut MetadataReport>,
+) {
+    apply_severity_map(
+        &compose_severity_maps(contract_map, global_map),
+        branch_reports,
+        required_report.as_deref_mut(),
+        metadata_report.as_deref_mut(),
+    );
+    apply_exceptions(exceptions, branch_reports, required_report, metadata_report);
+}
+
+pub fn apply_severity_map(
+    map: &SeverityMap,
+    branch_reports: &mut [BranchProtectionReport],
+    required_report: Option<&mut RequiredFilesReport>,
+    metadata_report: Option<&mut MetadataReport>,
+) {
+    if map.is_empty() {
+        return;
+    }
+    for report in branch_reports {
+        for detail in &mut report.details {
+            detail.severity = detail.severity.remap(map);
+        }
+        for check in &mut report.checks {
+            check.severity = check.severity.remap(map);
+        }
+    }
+    if let Some(report) = required_report {
+        for check in &mut report.checks {
+            check.severity = check.severity.remap(map);
+        }
+        report.recount();
+    }
+    if let Some(report) = metadata_report {
+        for check in &mut report.checks {
+            check.severity = check.severity.remap(map);
+        }
+    }
+}
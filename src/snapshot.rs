@@ -0,0 +1,84 @@
+use crate::{
+    BranchProtectionRules, ContractError, ContractResult, Forge, ForgeKind, RepositorySettings,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositorySnapshot {
+    pub version: u32,
+    #[serde(default)]
+    pub forge: ForgeKind,
+    pub repository: String,
+    pub branches: Vec<String>,
+    #[serde(default)]
+    pub protections: BTreeMap<String, Option<BranchProtectionRules>>,
+    #[serde(default)]
+    pub settings: Option<RepositorySettings>,
+}
+
+impl Forge for RepositorySnapshot {
+    fn kind(&self) -> ForgeKind {
+        self.forge
+    }
+
+    fn list_branches(&self, _repo: &str) -> ContractResult<Vec<String>> {
+        Ok(self.branches.clone())
+    }
+
+    fn get_branch_protection(
+        &self,
+        _repo: &str,
+        branch: &str,
+    ) -> ContractResult<Option<BranchProtectionRules>> {
+        self.protections.get(branch).cloned().ok_or_else(|| {
+            ContractError::InvalidConfig(format!(
+                "snapshot does not contain branch protection for {branch}"
+            ))
+        })
+    }
+
+    fn list_files(&self, _repo: &str, _reference: Option<&str>) -> ContractResult<Vec<String>> {
+        Err(ContractError::InvalidConfig(
+            "snapshot does not contain repository files".to_string(),
+        ))
+    }
+
+    fn get_repository_settings(&self, _repo: &str) -> ContractResult<RepositorySettings> {
+        self.settings.clone().ok_or_else(|| {
+            ContractError::InvalidConfig(
+                "snapshot does not contain repository settings".to_string(),
+            )
+        })
+    }
+}
+
+pub fn capture_snapshot(forge: &dyn Forge, repo: &str) -> ContractResult<RepositorySnapshot> {
+    let branches = forge.list_branches(repo)?;
+    let fetched = forge.get_branch_protections(repo, &branches)?;
+    let protections = branches.iter().cloned().zip(fetched).collect();
+    let settings = forge.get_repository_settings(repo)?;
+    Ok(RepositorySnapshot {
+        version: SNAPSHOT_VERSION,
+        forge: forge.kind(),
+        repository: repo.to_string(),
+        branches,
+        protections,
+        settings: Some(settings),
+    })
+}
+
+pub fn load_snapshot(path: &Path) -> ContractResult<RepositorySnapshot> {
+    let content = std::fs::read_to_string(path)?;
+    let snapshot: RepositorySnapshot = serde_json::from_str(&content)?;
+    if snapshot.version != SNAPSHOT_VERSION {
+        return Err(ContractError::InvalidConfig(format!(
+            "unsupported snapshot version: {}",
+            snapshot.version
+        )));
+    }
+    Ok(snapshot)
+}
@@ -0,0 +1,399 @@
+use crate::{ContractFormat, ContractResult};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use toml_edit::{ImDocument, Item, Key, Table, Value as TomlValue};
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::Marker;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum YamlPathSegment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    path: PathBuf,
+    content: String,
+    root: Option<SourceNode>,
+}
+
+/// A parsed document node with the position where it starts in the file.
+#[derive(Debug, Clone)]
+struct SourceNode {
+    line: usize,
+    column: usize,
+    kind: NodeKind,
+}
+
+#[derive(Debug, Clone)]
+enum NodeKind {
+    Scalar,
+    Sequence(Vec<SourceNode>),
+    Mapping(Vec<MappingEntry>),
+}
+
+#[derive(Debug, Clone)]
+struct MappingEntry {
+    key: String,
+    line: usize,
+    column: usize,
+    value: SourceNode,
+}
+
+impl SourceFile {
+    pub fn load(path: &Path) -> ContractResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let root = match ContractFormat::from_path(path) {
+            ContractFormat::Toml => parse_toml(&content),
+            // JSON documents are valid YAML flow collections.
+            ContractFormat::Yaml | ContractFormat::Json => parse_yaml(&content),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            content,
+            root,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn line(&self, number: usize) -> Option<&str> {
+        self.content.lines().nth(number.checked_sub(1)?)
+    }
+
+    /// Returns the location of the deepest segment of `path` present in the file.
+    pub fn locate(&self, path: &[YamlPathSegment<'_>]) -> Option<SourceLocation> {
+        let mut node = self.root.as_ref()?;
+        let mut found = None;
+        for segment in path {
+            let next = match (&node.kind, segment) {
+                (NodeKind::Mapping(entries), YamlPathSegment::Key(key)) => entries
+                    .iter()
+                    .find(|entry| entry.key == *key)
+                    .map(|entry| (entry.line, entry.column, &entry.value)),
+                (NodeKind::Mapping(entries), YamlPathSegment::Index(index)) => entries
+                    .iter()
+                    .find(|entry| entry.key == index.to_string())
+                    .map(|entry| (entry.line, entry.column, &entry.value)),
+                (NodeKind::Sequence(items), YamlPathSegment::Index(index)) => {
+                    items.get(*index).map(|item| (item.line, item.column, item))
+                }
+                _ => None,
+            };
+            let Some((line, column, child)) = next else {
+                break;
+            };
+            found = Some(self.location(line, column));
+            node = child;
+        }
+        found
+    }
+
+    pub fn locate_pointer(&self, pointer: &str) -> Option<SourceLocation> {
+        let segments = pointer_segments(pointer);
+        if segments.is_empty() {
+            return Some(self.location(1, 1));
+        }
+        self.locate(&segments)
+    }
+
+    /// Locates the value at `pointer` only when the file wrote it: either the
+    /// pointer exists, or it runs into a scalar (a `${VAR}` reference) that
+    /// expanded into the value.
+    pub(crate) fn locate_value(&self, pointer: &str) -> Option<SourceLocation> {
+        let mut node = self.root.as_ref()?;
+        let mut found = None;
+        for segment in pointer_segments(pointer) {
+            let next = match (&node.kind, segment) {
+                (NodeKind::Scalar, _) => return found,
+                (NodeKind::Mapping(entries), YamlPathSegment::Key(key)) => entries
+                    .iter()
+                    .find(|entry| entry.key == key)
+                    .map(|entry| (entry.line, entry.column, &entry.value)),
+                (NodeKind::Mapping(entries), YamlPathSegment::Index(index)) => entries
+                    .iter()
+                    .find(|entry| entry.key == index.to_string())
+                    .map(|entry| (entry.line, entry.column, &entry.value)),
+                (NodeKind::Sequence(items), YamlPathSegment::Index(index)) => {
+                    items.get(index).map(|item| (item.line, item.column, item))
+                }
+                (NodeKind::Sequence(_), YamlPathSegment::Key(_)) => None,
+            };
+            let (line, column, child) = next?;
+            found = Some(self.location(line, column));
+            node = child;
+        }
+        found.or_else(|| Some(self.location(1, 1)))
+    }
+
+    fn location(&self, line: usize, column: usize) -> SourceLocation {
+        SourceLocation {
+            file: self.path.clone(),
+            line,
+            column,
+        }
+    }
+}
+
+fn pointer_segments(pointer: &str) -> Vec<YamlPathSegment<'_>> {
+    pointer
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| match segment.parse::<usize>() {
+            Ok(index) => YamlPathSegment::Index(index),
+            Err(_) => YamlPathSegment::Key(segment),
+        })
+        .collect()
+}
+
+fn parse_yaml(content: &str) -> Option<SourceNode> {
+    let mut builder = YamlTreeBuilder::default();
+    Parser::new_from_str(content)
+        .load(&mut builder, false)
+        .ok()?;
+    builder.root
+}
+
+enum YamlFrame {
+    Sequence {
+        line: usize,
+        column: usize,
+        items: Vec<SourceNode>,
+    },
+    Mapping {
+        line: usize,
+        column: usize,
+        entries: Vec<MappingEntry>,
+        key: Option<(String, usize, usize)>,
+    },
+}
+
+#[derive(Default)]
+struct YamlTreeBuilder {
+    stack: Vec<YamlFrame>,
+    root: Option<SourceNode>,
+}
+
+impl YamlTreeBuilder {
+    fn push(&mut self, node: SourceNode, scalar: Option<String>) {
+        match self.stack.last_mut() {
+            Some(YamlFrame::Sequence { items, .. }) => items.push(node),
+            Some(YamlFrame::Mapping { entries, key, .. }) => match key.take() {
+                Some((key, line, column)) => entries.push(MappingEntry {
+                    key,
+                    line,
+                    column,
+                    value: node,
+                }),
+                None => *key = Some((scalar.unwrap_or_default(), node.line, node.column)),
+            },
+            None => {
+                self.root.get_or_insert(node);
+            }
+        }
+    }
+}
+
+impl MarkedEventReceiver for YamlTreeBuilder {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        let (line, column) = (mark.line(), mark.col() + 1);
+        match event {
+            Event::Scalar(value, ..) => {
+                let node = SourceNode {
+                    line,
+                    column,
+                    kind: NodeKind::Scalar,
+                };
+                self.push(node, Some(value));
+            }
+            Event::Alias(_) => {
+                let node = SourceNode {
+                    line,
+                    column,
+                    kind: NodeKind::Scalar,
+                };
+                self.push(node, None);
+            }
+            Event::SequenceStart(..) => self.stack.push(YamlFrame::Sequence {
+                line,
+                column,
+                items: Vec::new(),
+            }),
+            Event::MappingStart(..) => self.stack.push(YamlFrame::Mapping {
+                line,
+                column,
+                entries: Vec::new(),
+                key: None,
+            }),
+            Event::SequenceEnd | Event::MappingEnd => {
+                let node = match self.stack.pop() {
+                    Some(YamlFrame::Sequence {
+                        line,
+                        column,
+                        items,
+                    }) => SourceNode {
+                        line,
+                        column,
+                        kind: NodeKind::Sequence(items),
+                    },
+                    Some(YamlFrame::Mapping {
+                        line,
+                        column,
+                        entries,
+                        ..
+                    }) => {
+                        // Block mappings are reported at their first value indicator, so
+                        // start them at the first key instead.
+                        let (line, column) = entries
+                            .first()
+                            .map(|entry| (entry.line, entry.column))
+                            .filter(|first| *first < (line, column))
+                            .unwrap_or((line, column));
+                        SourceNode {
+                            line,
+                            column,
+                            kind: NodeKind::Mapping(entries),
+                        }
+                    }
+                    None => return,
+                };
+                self.push(node, None);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_toml(content: &str) -> Option<SourceNode> {
+    let document = ImDocument::parse(content).ok()?;
+    let positions = LinePositions::new(content);
+    Some(positions.table(document.as_table(), (1, 1)))
+}
+
+/// Converts byte offsets reported by `toml_edit` into 1-based lines and columns.
+struct LinePositions<'a> {
+    content: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> LinePositions<'a> {
+    fn new(content: &'a str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(content.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+        Self { content, starts }
+    }
+
+    fn position(&self, span: Option<Range<usize>>) -> Option<(usize, usize)> {
+        let offset = span?.start;
+        let line = self.starts.partition_point(|start| *start <= offset);
+        let start = self.starts[line - 1];
+        let column = self.content.get(start..offset)?.chars().count() + 1;
+        Some((line, column))
+    }
+
+    fn table(&self, table: &Table, fallback: (usize, usize)) -> SourceNode {
+        let entries = table
+            .iter()
+            .filter_map(|(key, _)| table.get_key_value(key))
+            .map(|(key, item)| self.entry(key, item, fallback))
+            .collect();
+        let (line, column) = self.position(table.span()).unwrap_or(fallback);
+        SourceNode {
+            line,
+            column,
+            kind: NodeKind::Mapping(entries),
+        }
+    }
+
+    fn entry(&self, key: &Key, item: &Item, fallback: (usize, usize)) -> MappingEntry {
+        let position = self
+            .position(key.span())
+            .or_else(|| self.position(item.span()))
+            .unwrap_or(fallback);
+        let value = self.item(item, position);
+        let (line, column) = self
+            .position(key.span())
+            .unwrap_or((value.line, value.column));
+        MappingEntry {
+            key: key.get().to_string(),
+            line,
+            column,
+            value,
+        }
+    }
+
+    fn item(&self, item: &Item, fallback: (usize, usize)) -> SourceNode {
+        match item {
+            Item::Table(table) => self.table(table, fallback),
+            Item::ArrayOfTables(tables) => {
+                let items = tables
+                    .iter()
+                    .map(|table| self.table(table, fallback))
+                    .collect::<Vec<_>>();
+                let (line, column) = items
+                    .first()
+                    .map(|item| (item.line, item.column))
+                    .unwrap_or(fallback);
+                SourceNode {
+                    line,
+                    column,
+                    kind: NodeKind::Sequence(items),
+                }
+            }
+            Item::Value(value) => self.value(value, fallback),
+            Item::None => SourceNode {
+                line: fallback.0,
+                column: fallback.1,
+                kind: NodeKind::Scalar,
+            },
+        }
+    }
+
+    fn value(&self, value: &TomlValue, fallback: (usize, usize)) -> SourceNode {
+        let (line, column) = self.position(value.span()).unwrap_or(fallback);
+        let kind = match value {
+            TomlValue::Array(array) => NodeKind::Sequence(
+                array
+                    .iter()
+                    .map(|value| self.value(value, (line, column)))
+                    .collect(),
+            ),
+            TomlValue::InlineTable(table) => NodeKind::Mapping(
+                table
+                    .iter()
+                    .filter_map(|(key, _)| table.get_key_value(key))
+                    .map(|(key, value)| {
+                        let value = self.item(value, (line, column));
+                        let (key_line, key_column) = self
+                            .position(key.span())
+                            .unwrap_or((value.line, value.column));
+                        MappingEntry {
+                            key: key.get().to_string(),
+                            line: key_line,
+                            column: key_column,
+                            value,
+                        }
+                    })
+                    .collect(),
+            ),
+            _ => NodeKind::Scalar,
+        };
+        SourceNode { line, column, kind }
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{BranchProtection, Contract, RequiredFile, Severity, SeverityMap, StatusCheck, Tier};
+use serde::Deserialize;
+use serde_yaml::{Mapping, Value};
+use std::collections::BTreeMap;
+
+pub const BUILTIN_TIERS: &[&str] = &["critical", "standard", "experimental"];
+
+const CRITICAL_PRESET: &str = r#"
+branch_protection:
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 2
+      dismiss_stale_reviews: true
+    enforce_admins: true
+    required_signatures: true
+severity_map:
+  warning: error
+"#;
+
+const EXPERIMENTAL_PRESET: &str = r#"
+severity_map:
+  error: warning
+"#;
+
+/// Baseline applied under a contract that declares `metadata.tier`.
+///
+/// Scalars fill only the fields the contract leaves out, `branches` and
+/// `checks` are replaced as a whole, severity maps merge per key with the
+/// contract winning, and `required_files` are appended after the contract's
+/// own entries unless the same path or pattern is already listed.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct TierPreset {
+    branch_protection: Option<PresetBranchProtection>,
+    required_files: Vec<RequiredFile>,
+    severity_map: SeverityMap,
+    /// Pointer of a user-defined preset under `tiers`.
+    #[serde(skip)]
+    pointer: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PresetBranchProtection {
+    branches: Option<Vec<String>>,
+    rules: PresetRules,
+    severity: BTreeMap<String, Severity>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PresetRules {
+    required_pull_request_reviews: PresetReviews,
+    required_status_checks: PresetStatusChecks,
+    enforce_admins: Option<bool>,
+    required_linear_history: Option<bool>,
+    allow_force_pushes: Option<bool>,
+    allow_deletions: Option<bool>,
+    required_conversation_resolution: Option<bool>,
+    required_signatures: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PresetReviews {
+    enabled: Option<bool>,
+    required_approving_review_count: Option<u8>,
+    dismiss_stale_reviews: Option<bool>,
+    require_code_owner_reviews: Option<bool>,
+    require_last_push_approval: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PresetStatusChecks {
+    enabled: Option<bool>,
+    strict: Option<bool>,
+    checks: Option<Vec<StatusCheck>>,
+}
+
+impl TierPreset {
+    pub(crate) fn pointer(&self) -> Option<&str> {
+        self.pointer.as_deref()
+    }
+
+    /// Applies the preset to `contract`; `document` is the resolved source
+    /// used to tell which fields the contract set explicitly.
+    ///
+    /// Returns the contract pointers the preset filled, each paired with the
+    /// pointer of the value inside the preset.
+    pub(crate) fn apply(&self, contract: &mut Contract, document: &Value) -> Vec<(String, String)> {
+        let mut filled = Vec::new();
+        if let Some(preset) = &self.branch_protection {
+            let protection = contract.branch_protection.get_or_insert_with(|| {
+                serde_yaml::from_value(Value::Mapping(Mapping::new()))
+                    .expect("empty branch_protection uses defaults")
+            });
+            preset.apply(protection, document, &mut filled);
+        }
+
+        for (index, file) in self.required_files.iter().enumerate() {
+            let listed = contract.required_files.iter().any(|existing| {
+                (file.path.is_some() && existing.path == file.path)
+                    || (file.pattern.is_some() && existing.pattern == file.pattern)
+            });
+            if !listed {
+                filled.push((
+                    format!("/required_files/{}", contract.required_files.len()),
+                    format!("/required_files/{index}"),
+                ));
+                contract.required_files.push(file.clone());
+            }
+        }
+
+        for (from, to) in &self.severity_map {
+            if !contract.severity_map.contains_key(from) {
+                let pointer = format!("/severity_map/{}", from.as_str());
+                filled.push((pointer.clone(), pointer));
+                contract.severity_map.insert(*from, *to);
+            }
+        }
+        filled
+    }
+}
+
+impl PresetBranchProtection {
+    fn apply(
+        &self,
+        protection: &mut BranchProtection,
+        document: &Value,
+        filled: &mut Vec<(String, String)>,
+    ) {
+        let mut fill = Fill {
+            document,
+            prefix: &["branch_protection"],
+            filled,
+        };
+        fill.set(&mut protection.branches, &self.branches, &["branches"]);
+        for (path, severity) in &self.severity {
+            if !protection.severity.contains_key(path) {
+                let pointer = format!("/branch_protection/severity/{path}");
+                fill.filled.push((pointer.clone(), pointer));
+                protection.severity.insert(path.clone(), *severity);
+            }
+        }
+
+        let rules = &mut protection.rules;
+        let preset = &self.rules;
+        let mut fill = Fill {
+            document,
+            prefix: &["branch_protection", "rules"],
+            filled: fill.filled,
+        };
+        fill.set(
+            &mut rules.enforce_admins,
+            &preset.enforce_admins,
+            &["enforce_admins"],
+        );
+        fill.set(
+            &mut rules.required_linear_history,
+            &preset.required_linear_history,
+            &["required_linear_history"],
+        );
+        fill.set(
+            &mut rules.allow_force_pushes,
+            &preset.allow_force_pushes,
+            &["allow_force_pushes"],
+        );
+        fill.set(
+            &mut rules.allow_deletions,
+            &preset.allow_deletions,
+            &["allow_deletions"],
+        );
+        fill.set(
+            &mut rules.required_conversation_resolution,
+            &preset.required_conversation_resolution,
+            &["required_conversation_resolution"],
+        );
+        fill.set(
+            &mut rules.required_signatures,
+            &preset.required_signatures,
+            &["required_signatures"],
+        );
+
+        let reviews = &mut rules.required_pull_request_reviews;
+        let preset_reviews = &preset.required_pull_request_reviews;
+        let section = "required_pull_request_reviews";
+        fill.set(
+            &mut reviews.enabled,
+            &preset_reviews.enabled,
+            &[section, "enabled"],
+        );
+        fill.set(
+            &mut reviews.required_approving_review_count,
+            &preset_reviews.required_approving_review_count,
+            &[section, "required_approving_review_count"],
+        );
+        fill.set(
+            &mut reviews.dismiss_stale_reviews,
+            &preset_reviews.dismiss_stale_reviews,
+            &[section, "dismiss_stale_reviews"],
+        );
+        fill.set(
+            &mut reviews.require_code_owner_reviews,
+            &preset_reviews.require_code_owner_reviews,
+            &[section, "require_code_owner_reviews"],
+        );
+        fill.set(
+            &mut reviews.require_last_push_approval,
+            &preset_reviews.require_last_push_approval,
+            &[section, "require_last_push_approval"],
+        );
+
+        let checks = &mut rules.required_status_checks;
+        let preset_checks = &preset.required_status_checks;
+        let section = "required_status_checks";
+        fill.set(
+            &mut checks.enabled,
+            &preset_checks.enabled,
+            &[section, "enabled"],
+        );
+        fill.set(
+            &mut checks.strict,
+            &preset_checks.strict,
+            &[section, "strict"],
+        );
+        fill.set(
+            &mut checks.checks,
+            &preset_checks.checks,
+            &[section, "checks"],
+        );
+    }
+}
+
+struct Fill<'a> {
+    document: &'a Value,
+    prefix: &'a [&'a str],
+    filled: &'a mut Vec<(String, String)>,
+}
+
+impl Fill<'_> {
+    /// Replaces `target` with the preset value unless the document sets the field.
+    fn set<T: Clone>(&mut self, target: &mut T, preset: &Option<T>, path: &[&str]) {
+        let Some(value) = preset else {
+            return;
+        };
+        let mut node = Some(self.document);
+        let mut pointer = String::new();
+        for key in self.prefix.iter().chain(path) {
+            node = node.and_then(|node| node.get(*key));
+            pointer.push('/');
+            pointer.push_str(key);
+        }
+        if node.is_none() {
+            *target = value.clone();
+            self.filled.push((pointer.clone(), pointer));
+        }
+    }
+}
+
+/// Resolves the preset for `tier`: `None` when the tier is unknown, an error
+/// message when a user-defined preset does not match the preset shape.
+pub(crate) fn tier_preset(
+    tier: &Tier,
+    user_tiers: Option<&Mapping>,
+) -> Option<Result<TierPreset, String>> {
+    if let Some(preset) = user_tiers.and_then(|tiers| tiers.get(tier.as_str())) {
+        return Some(
+            serde_yaml::from_value(preset.clone())
+                .map(|preset| TierPreset {
+                    pointer: Some(format!("/tiers/{tier}")),
+                    ..preset
+                })
+                .map_err(|error| error.to_string()),
+        );
+    }
+    let builtin = match tier {
+        Tier::Critical => CRITICAL_PRESET,
+        Tier::Standard => return Some(Ok(TierPreset::default())),
+        Tier::Experimental => EXPERIMENTAL_PRESET,
+        Tier::Custom(_) => return None,
+    };
+    Some(Ok(
+        serde_yaml::from_str(builtin).expect("built-in tier presets are valid")
+    ))
+}
@@ -1,13 +1,26 @@
-use crate::{schema_json, ContractError, ContractResult};
+use crate::interpolation::{resolve_document, Scope};
+use crate::loader::load_declared;
+use crate::origin::ContractOrigins;
+use crate::{
+    schema_json, schema_json_for_version, ContractError, ContractFormat, ContractResult,
+    RequiredFile, SourceFile, SourceLocation, BUILTIN_TIERS,
+};
+use globset::GlobBuilder;
 use jsonschema::JSONSchema;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationIssue {
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instance_path: Option<String>,
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,31 +31,304 @@ pub struct ValidationReport {
 }
 
 pub fn validate_contract_file(path: &Path) -> ContractResult<ValidationReport> {
-    let content = std::fs::read_to_string(path)?;
-    let yaml_value: serde_yaml::Value = serde_yaml::from_str(&content)?;
-    let json_value = serde_json::to_value(yaml_value)?;
-    let schema_value: serde_json::Value = serde_json::from_str(schema_json())?;
-    let compiled = JSONSchema::compile(&schema_value)
-        .map_err(|error| ContractError::InvalidConfig(error.to_string()))?;
-    let report = match compiled.validate(&json_value) {
-        Ok(_) => ValidationReport {
-            path: path.display().to_string(),
-            valid: true,
-            errors: Vec::new(),
-        },
-        Err(errors) => {
-            let issues = errors
-                .map(|error| ValidationIssue {
-                    message: error.to_string(),
-                    instance_path: Some(error.instance_path.to_string()),
-                })
-                .collect::<Vec<_>>();
-            ValidationReport {
-                path: path.display().to_string(),
-                valid: false,
-                errors: issues,
+    validate_file(path, &Scope::default())
+}
+
+/// Validates a profile with the tier and `vars` it inherits from `base_path`.
+pub fn validate_profile_file(path: &Path, base_path: &Path) -> ContractResult<ValidationReport> {
+    let raw = ContractFormat::from_path(base_path).parse(&std::fs::read_to_string(base_path)?)?;
+    let base = resolve_document(raw, &Scope::default());
+    validate_file(path, &base.scope)
+}
+
+fn validate_file(path: &Path, inherited: &Scope) -> ContractResult<ValidationReport> {
+    let source = SourceFile::load(path)?;
+    let raw: serde_yaml::Value = ContractFormat::from_path(path).parse(source.content())?;
+    let raw_json = serde_json::to_value(&raw)?;
+    let resolved = resolve_document(raw, inherited);
+    let origins = ContractOrigins::for_file(Arc::new(source), &resolved.overlays);
+    let mut json_value = serde_json::to_value(resolved.document)?;
+    if let Some(object) = json_value.as_object_mut() {
+        for key in ["vars", "when", "tiers"] {
+            if let Some(block) = raw_json.get(key) {
+                object.insert(key.to_string(), block.clone());
             }
         }
+    }
+    let schema = match json_value.get("version").and_then(Value::as_str) {
+        Some(version) => schema_json_for_version(version),
+        None => Ok(schema_json()),
+    };
+    let mut findings = resolved
+        .undefined
+        .into_iter()
+        .map(|variable| {
+            (
+                variable.pointer,
+                format!("Undefined variable ${{{}}}", variable.name),
+            )
+        })
+        .collect::<Vec<_>>();
+    if let Some(tier) = &resolved.unknown_tier {
+        findings.push((
+            "/metadata/tier".to_string(),
+            format!(
+                "Unknown tier \"{tier}\" (use one of {} or define it under tiers)",
+                BUILTIN_TIERS.join(", ")
+            ),
+        ));
+    }
+    if let Some((tier, message)) = &resolved.invalid_preset {
+        findings.push((
+            format!("/tiers/{tier}"),
+            format!("Invalid tier preset \"{tier}\": {message}"),
+        ));
+    }
+    match schema {
+        Ok(schema) => {
+            let schema_value: serde_json::Value = serde_json::from_str(schema)?;
+            let compiled = JSONSchema::compile(&schema_value)
+                .map_err(|error| ContractError::InvalidConfig(error.to_string()))?;
+            if let Err(errors) = compiled.validate(&json_value) {
+                findings.extend(
+                    errors.map(|error| (error.instance_path.to_string(), error.to_string())),
+                );
+            }
+            findings.extend(lint_contract(&json_value));
+        }
+        Err(error @ ContractError::UnsupportedVersion(_)) => {
+            findings.push(("/version".to_string(), error.to_string()));
+        }
+        Err(error) => return Err(error),
+    }
+    let issues = findings
+        .into_iter()
+        .map(|(instance_path, message)| ValidationIssue {
+            message,
+            location: origins.locate(&instance_path),
+            instance_path: Some(instance_path),
+        })
+        .collect::<Vec<_>>();
+    Ok(ValidationReport {
+        path: path.display().to_string(),
+        valid: issues.is_empty(),
+        errors: issues,
+    })
+}
+
+pub fn validate_merged_contract(config_path: &Path) -> ContractResult<ValidationReport> {
+    let (base, profile) = load_declared(config_path)?;
+    let mut issues = Vec::new();
+
+    let base_files = &base.required_files;
+    let profile_files = profile
+        .as_ref()
+        .map(|(_, profile)| profile.required_files.as_slice())
+        .unwrap_or_default();
+    for (index, required) in profile_files.iter().enumerate() {
+        let Some((key, field)) = required_file_key(required) else {
+            continue;
+        };
+        let duplicate = base_files
+            .iter()
+            .position(|candidate| required_file_key(candidate) == Some((key, field)));
+        if let Some(first) = duplicate {
+            issues.push(ValidationIssue {
+                message: format!(
+                    "Required file \"{key}\" is already listed at /required_files/{first} in {}",
+                    config_path.display()
+                ),
+                instance_path: Some(format!("/required_files/{index}/{field}")),
+                location: required.location.clone(),
+            });
+        }
+    }
+
+    if let (Some(base_protection), Some(profile_protection)) = (
+        &base.branch_protection,
+        profile
+            .as_ref()
+            .and_then(|(_, profile)| profile.branch_protection.as_ref()),
+    ) {
+        if serde_json::to_value(base_protection)? != serde_json::to_value(profile_protection)? {
+            issues.push(ValidationIssue {
+                message: format!(
+                    "branch_protection in the profile replaces the one defined in {}",
+                    config_path.display()
+                ),
+                instance_path: Some("/branch_protection".to_string()),
+                location: profile_protection
+                    .location_for("branch_protection")
+                    .cloned(),
+            });
+        }
+    }
+
+    let path = match &profile {
+        Some((profile_path, _)) => format!(
+            "{} + {} (merged)",
+            config_path.display(),
+            profile_path.display()
+        ),
+        None => format!("{} (merged)", config_path.display()),
+    };
+    Ok(ValidationReport {
+        path,
+        valid: issues.is_empty(),
+        errors: issues,
+    })
+}
+
+fn required_file_key(required: &RequiredFile) -> Option<(&str, &'static str)> {
+    match (&required.path, &required.pattern) {
+        (Some(path), _) => Some((path, "path")),
+        (None, Some(pattern)) => Some((pattern, "pattern")),
+        (None, None) => None,
+    }
+}
+
+fn lint_contract(contract: &Value) -> Vec<(String, String)> {
+    let mut issues = Vec::new();
+    lint_branch_protection(contract, &mut issues);
+    lint_required_files(contract, &mut issues);
+    issues
+}
+
+fn lint_branch_protection(contract: &Value, issues: &mut Vec<(String, String)>) {
+    let Some(branch_protection) = contract.get("branch_protection") else {
+        return;
+    };
+    let mut globs = string_items(branch_protection.get("branches"))
+        .map(|(index, branch)| (format!("/branch_protection/branches/{index}"), branch))
+        .collect::<Vec<_>>();
+    let overrides = branch_protection
+        .get("branch_severity")
+        .and_then(Value::as_array);
+    for (index, branch_override) in overrides.into_iter().flatten().enumerate() {
+        globs.extend(
+            string_items(branch_override.get("branches")).map(|(item, branch)| {
+                (
+                    format!("/branch_protection/branch_severity/{index}/branches/{item}"),
+                    branch,
+                )
+            }),
+        );
+    }
+    for (pointer, branch) in globs {
+        if let Err(error) = GlobBuilder::new(branch).literal_separator(true).build() {
+            issues.push((
+                pointer,
+                format!("Invalid branch glob \"{branch}\": {error}"),
+            ));
+        }
+    }
+
+    let Some(rules) = branch_protection.get("rules") else {
+        return;
     };
-    Ok(report)
+    if let Some(reviews) = rules.get("required_pull_request_reviews") {
+        let disabled = reviews.get("enabled").and_then(Value::as_bool) == Some(false);
+        if disabled && reviews.get("required_approving_review_count").is_some() {
+            issues.push((
+                "/branch_protection/rules/required_pull_request_reviews/required_approving_review_count"
+                    .to_string(),
+                "required_approving_review_count has no effect while required_pull_request_reviews is disabled"
+                    .to_string(),
+            ));
+        }
+    }
+
+    let checks = rules
+        .get("required_status_checks")
+        .and_then(|status_checks| status_checks.get("checks"))
+        .and_then(Value::as_array);
+    let mut seen = HashMap::new();
+    for (index, check) in checks.into_iter().flatten().enumerate() {
+        let Some(context) = check.get("context").and_then(Value::as_str) else {
+            continue;
+        };
+        if let Some(first) = seen.get(context) {
+            issues.push((
+                format!("/branch_protection/rules/required_status_checks/checks/{index}/context"),
+                format!(
+                    "Duplicate status check \"{context}\" (already listed at /branch_protection/rules/required_status_checks/checks/{first})"
+                ),
+            ));
+        } else {
+            seen.insert(context, index);
+        }
+    }
+}
+
+fn lint_required_files(contract: &Value, issues: &mut Vec<(String, String)>) {
+    let Some(required_files) = contract.get("required_files").and_then(Value::as_array) else {
+        return;
+    };
+    let mut seen = HashMap::new();
+    for (index, required) in required_files.iter().enumerate() {
+        let pointer = format!("/required_files/{index}");
+        let path = required.get("path").and_then(Value::as_str);
+        let pattern = required.get("pattern").and_then(Value::as_str);
+        if path.is_some() && pattern.is_some() {
+            issues.push((
+                pointer.clone(),
+                "Required file entry must not set both path and pattern".to_string(),
+            ));
+        }
+        if let Some(path) = path {
+            lint_file_glob(path, format!("{pointer}/path"), issues);
+        }
+        for (alternative_index, alternative) in string_items(required.get("alternatives")) {
+            lint_file_glob(
+                alternative,
+                format!("{pointer}/alternatives/{alternative_index}"),
+                issues,
+            );
+        }
+        if let Some(pattern) = pattern {
+            if let Err(error) = Regex::new(pattern) {
+                issues.push((
+                    format!("{pointer}/pattern"),
+                    format!("Invalid regular expression \"{pattern}\": {error}"),
+                ));
+            }
+        }
+
+        let key = match (path, pattern) {
+            (Some(path), _) => (path, "path"),
+            (None, Some(pattern)) => (pattern, "pattern"),
+            (None, None) => continue,
+        };
+        if let Some(first) = seen.get(&key) {
+            issues.push((
+                format!("{pointer}/{}", key.1),
+                format!(
+                    "Duplicate required file \"{}\" (already listed at /required_files/{first})",
+                    key.0
+                ),
+            ));
+        } else {
+            seen.insert(key, index);
+        }
+    }
+}
+
+fn lint_file_glob(candidate: &str, pointer: String, issues: &mut Vec<(String, String)>) {
+    let looks_like_glob =
+        candidate.contains('*') || candidate.contains('?') || candidate.contains('[');
+    if !looks_like_glob {
+        return;
+    }
+    if let Err(error) = GlobBuilder::new(candidate).build() {
+        issues.push((pointer, format!("Invalid glob \"{candidate}\": {error}")));
+    }
+}
+
+fn string_items(value: Option<&Value>) -> impl Iterator<Item = (usize, &str)> {
+    value
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(index, item)| item.as_str().map(|item| (index, item)))
 }
@@ -0,0 +1,36 @@
+use crate::{ContractError, ContractResult};
+use serde::Serialize;
+use std::fmt;
+
+pub const CURRENT_CONTRACT_VERSION: ContractVersion = ContractVersion { major: 1, minor: 0 };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ContractVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ContractVersion {
+    pub fn parse(value: &str) -> ContractResult<Self> {
+        let invalid = || ContractError::UnsupportedVersion(value.to_string());
+        let (major, minor) = value.split_once('.').ok_or_else(invalid)?;
+        Ok(Self {
+            major: major.parse().map_err(|_| invalid())?,
+            minor: minor.parse().map_err(|_| invalid())?,
+        })
+    }
+
+    pub fn ensure_supported(self) -> ContractResult<Self> {
+        if self.major == CURRENT_CONTRACT_VERSION.major && self <= CURRENT_CONTRACT_VERSION {
+            Ok(self)
+        } else {
+            Err(ContractError::UnsupportedVersion(self.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for ContractVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}.{}", self.major, self.minor)
+    }
+}
@@ -0,0 +1,133 @@
+use repo_contract::result_schema_json;
+use std::path::Path;
+use std::process::Command;
+
+const STATE: &str = r#"{
+  "version": 1,
+  "repository": "owner/repo",
+  "branches": ["main"],
+  "protections": {
+    "main": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1
+      }
+    }
+  }
+}"#;
+
+fn contract(review_count: u32, required_files: &[&str]) -> String {
+    let mut contract = format!(
+        "version: \"1.0\"\nbranch_protection:\n  branches: [\"main\"]\n  rules:\n    required_pull_request_reviews:\n      required_approving_review_count: {review_count}\nrequired_files:\n"
+    );
+    for path in required_files {
+        contract.push_str(&format!("  - path: \"{path}\"\n"));
+    }
+    contract
+}
+
+fn run_check(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(dir)
+        .env_remove("GITHUB_STEP_SUMMARY")
+        .env_remove("GITHUB_OUTPUT")
+        .args(["check", "--state", "state.json"])
+        .args(args)
+        .output()
+        .expect("run repo-contract")
+}
+
+fn write_baseline(temp: &tempfile::TempDir) {
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        contract(2, &["README.md", "SECURITY.md"]),
+    )
+    .expect("write contract");
+    let output = run_check(temp.path(), &["--write-baseline", "contract.baseline.json"]);
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn write_baseline_records_current_failures() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    write_baseline(&temp);
+
+    let baseline: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(temp.path().join("contract.baseline.json")).expect("baseline"),
+    )
+    .expect("baseline json");
+    assert_eq!(baseline["version"], 1);
+    let entries = baseline["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .map(|entry| {
+            (
+                entry["rule"].as_str().unwrap_or_default(),
+                entry["target"].as_str().unwrap_or_default(),
+                entry["fingerprint"].as_str().unwrap_or_default().len(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            ("branch_protection", "main", 64),
+            ("required_files", "README.md", 64),
+            ("required_files", "SECURITY.md", 64),
+        ]
+    );
+}
+
+#[test]
+fn check_with_baseline_fails_only_on_new_violations() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    write_baseline(&temp);
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        contract(3, &["README.md", "SECURITY.md", "CODEOWNERS"]),
+    )
+    .expect("write contract");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+
+    let output = run_check(
+        temp.path(),
+        &["--baseline", "contract.baseline.json", "--format", "json"],
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        result["summary"],
+        serde_json::json!({ "error": 1, "warning": 0, "info": 0, "baselined": 2 })
+    );
+    let new = result["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .filter(|finding| finding["passed"] == false && finding.get("baselined").is_none())
+        .map(|finding| finding["target"].as_str().unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert_eq!(new, vec!["CODEOWNERS"]);
+    assert_eq!(result["fixed"].as_array().map(Vec::len), Some(1));
+    assert_eq!(result["fixed"][0]["target"], "README.md");
+
+    let schema: serde_json::Value =
+        serde_json::from_str(result_schema_json()).expect("result schema");
+    let compiled = jsonschema::JSONSchema::compile(&schema).expect("compile schema");
+    assert!(compiled.is_valid(&result));
+
+    std::fs::remove_file(temp.path().join("README.md")).expect("remove readme");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        contract(2, &["SECURITY.md"]),
+    )
+    .expect("write contract");
+    let output = run_check(temp.path(), &["--baseline", "contract.baseline.json"]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("○ SECURITY.md: Not found (error) [baseline]"));
+    assert!(stdout.contains("Fixed since baseline\n  ✓ required_files README.md\n"));
+    assert!(stdout.contains("Summary: 0 error, 0 warning, 0 info, 2 baselined"));
+}
@@ -0,0 +1,97 @@
+mod support;
+
+use repo_contract::{CheckConclusion, Summary};
+use std::process::Command;
+use support::{StubResponse, StubServer};
+
+#[test]
+fn conclusion_follows_summary_and_strict() {
+    let summary = |error, warning| Summary {
+        error,
+        warning,
+        info: 3,
+        waived: 0,
+        baselined: 0,
+    };
+
+    assert_eq!(
+        CheckConclusion::from_summary(&summary(1, 0), false),
+        CheckConclusion::Failure
+    );
+    assert_eq!(
+        CheckConclusion::from_summary(&summary(0, 2), false),
+        CheckConclusion::Neutral
+    );
+    assert_eq!(
+        CheckConclusion::from_summary(&summary(0, 2), true),
+        CheckConclusion::Failure
+    );
+    assert_eq!(
+        CheckConclusion::from_summary(&summary(0, 0), true),
+        CheckConclusion::Success
+    );
+}
+
+#[test]
+fn check_publishes_check_run_with_batched_annotations() {
+    let server = StubServer::start(|request| match request.method.as_str() {
+        "POST" => StubResponse::json(201, r#"{"id": 99}"#),
+        _ => StubResponse::json(200, r#"{"id": 99}"#),
+    });
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    let mut contract = "version: \"1.0\"\nrequired_files:\n".to_string();
+    for index in 0..55 {
+        contract.push_str(&format!("  - path: \"missing-{index}.md\"\n"));
+    }
+    std::fs::write(temp.path().join("contract.yml"), contract).expect("write contract");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env_remove("GITHUB_ACTIONS")
+        .env("GITHUB_TOKEN", "token")
+        .env("GITHUB_API_URL", &server.base_url)
+        .env("GITHUB_REPOSITORY", "owner/repo")
+        .args([
+            "--no-cache",
+            "check",
+            "--rules",
+            "required_files",
+            "--format",
+            "json",
+            "--publish-check-run",
+            "--head-sha",
+            "abc123",
+        ])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(1));
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/repos/owner/repo/check-runs");
+    let created: serde_json::Value = serde_json::from_str(&requests[0].body).expect("json body");
+    assert_eq!(created["head_sha"], "abc123");
+    assert_eq!(created["status"], "completed");
+    assert_eq!(created["conclusion"], "failure");
+    assert_eq!(created["output"]["title"], "55 error, 0 warning, 0 info");
+    let annotations = created["output"]["annotations"]
+        .as_array()
+        .expect("annotations");
+    assert_eq!(annotations.len(), 50);
+    assert_eq!(annotations[0]["path"], "contract.yml");
+    assert_eq!(annotations[0]["start_line"], 3);
+    assert_eq!(annotations[0]["annotation_level"], "failure");
+    assert_eq!(annotations[0]["title"], "E001 missing-required-file");
+
+    assert_eq!(requests[1].method, "PATCH");
+    assert_eq!(requests[1].path, "/repos/owner/repo/check-runs/99");
+    let updated: serde_json::Value = serde_json::from_str(&requests[1].body).expect("json body");
+    assert_eq!(
+        updated["output"]["annotations"]
+            .as_array()
+            .expect("annotations")
+            .len(),
+        5
+    );
+}
@@ -0,0 +1,284 @@
+use repo_contract::{load_contract, validate_contract_file, LoadOptions};
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+profile: "rust"
+
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_pull_request_reviews:
+      enabled: true
+      required_approving_review_count: 2
+"#;
+
+const PROFILE: &str = r#"version: "1.0"
+required_files:
+  - path: "Cargo.toml"
+"#;
+
+const STATE: &str = r#"{
+  "version": 1,
+  "repository": "owner/repo",
+  "branches": ["main"],
+  "protections": {
+    "main": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1
+      }
+    }
+  }
+}"#;
+
+fn workspace() -> tempfile::TempDir {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("contract.rust.yml"), PROFILE).expect("write profile");
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+    temp
+}
+
+#[test]
+fn loaded_contract_tracks_rule_locations_across_profile() {
+    let temp = workspace();
+    let loaded = load_contract(LoadOptions {
+        config_path: temp.path().join("contract.yml"),
+        include_profile: true,
+    })
+    .expect("load");
+
+    let required = loaded.contract.required_files[0]
+        .location
+        .as_ref()
+        .expect("required file location");
+    assert_eq!(required.file, temp.path().join("contract.rust.yml"));
+    assert_eq!((required.line, required.column), (3, 5));
+
+    let branch_protection = loaded
+        .contract
+        .branch_protection
+        .expect("branch protection");
+    let count = branch_protection
+        .location_for("required_pull_request_reviews.required_approving_review_count")
+        .expect("count location");
+    assert_eq!((count.line, count.column), (9, 7));
+    let fallback = branch_protection
+        .location_for("required_status_checks.strict")
+        .expect("fallback location");
+    assert_eq!((fallback.line, fallback.column), (6, 3));
+}
+
+fn load_locations(name: &str, content: &str) -> repo_contract::Contract {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join(name), content).expect("write contract");
+    load_contract(LoadOptions {
+        config_path: temp.path().join(name),
+        include_profile: false,
+    })
+    .expect("load")
+    .contract
+}
+
+fn position(location: Option<&repo_contract::SourceLocation>) -> Option<(usize, usize)> {
+    location.map(|location| (location.line, location.column))
+}
+
+#[test]
+fn locations_ignore_block_scalars_and_follow_flow_style() {
+    let contract = load_locations(
+        "contract.yml",
+        r#"version: "1.0"
+metadata:
+  description: |
+    Notes:
+    required_files: not a key
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_pull_request_reviews: {enabled: true, required_approving_review_count: 2}
+required_files:
+  - path: "README.md"
+    description: >
+      severity: folded text
+  - {path: "LICENSE", severity: warning}
+"#,
+    );
+
+    let metadata = contract.metadata.as_ref().expect("metadata");
+    assert_eq!(
+        position(metadata.locations.get("description")),
+        Some((3, 3))
+    );
+    assert!(metadata
+        .locations
+        .keys()
+        .all(|key| !key.contains("Notes") && !key.contains("required_files")));
+    let branch_protection = contract
+        .branch_protection
+        .as_ref()
+        .expect("branch protection");
+    assert_eq!(
+        position(
+            branch_protection
+                .location_for("required_pull_request_reviews.required_approving_review_count")
+        ),
+        Some((9, 52))
+    );
+    let required = contract
+        .required_files
+        .iter()
+        .map(|required| position(required.location.as_ref()))
+        .collect::<Vec<_>>();
+    assert_eq!(required, vec![Some((11, 5)), Some((14, 5))]);
+}
+
+#[test]
+fn locations_cover_json_and_toml_contracts() {
+    let json = load_locations(
+        "contract.json",
+        r#"{
+  "version": "1.0",
+  "required_files": [
+    {"path": "README.md"},
+    {"path": "LICENSE"}
+  ]
+}
+"#,
+    );
+    let required = json
+        .required_files
+        .iter()
+        .map(|required| position(required.location.as_ref()))
+        .collect::<Vec<_>>();
+    assert_eq!(required, vec![Some((4, 5)), Some((5, 5))]);
+
+    let toml = load_locations(
+        "contract.toml",
+        r#"version = "1.0"
+
+[branch_protection]
+branches = ["main"]
+
+[branch_protection.rules.required_pull_request_reviews]
+required_approving_review_count = 2
+
+[[required_files]]
+path = "README.md"
+
+[[required_files]]
+path = "LICENSE"
+"#,
+    );
+    let branch_protection = toml.branch_protection.as_ref().expect("branch protection");
+    assert_eq!(
+        position(
+            branch_protection
+                .location_for("required_pull_request_reviews.required_approving_review_count")
+        ),
+        Some((7, 1))
+    );
+    let required = toml
+        .required_files
+        .iter()
+        .map(|required| position(required.location.as_ref()))
+        .collect::<Vec<_>>();
+    assert_eq!(required, vec![Some((9, 1)), Some((12, 1))]);
+}
+
+#[test]
+fn validation_issues_point_at_offending_line() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    let path = temp.path().join("contract.yml");
+    std::fs::write(
+        &path,
+        "version: \"1.0\"\nrequired_files:\n  - path: \"README.md\"\n    severity: fatal\n",
+    )
+    .expect("write");
+
+    let report = validate_contract_file(&path).expect("validate");
+
+    assert!(!report.valid);
+    assert!(report.errors.iter().any(|issue| {
+        issue
+            .location
+            .as_ref()
+            .map(|location| location.line == 4 && location.column == 5)
+            .unwrap_or(false)
+    }));
+}
+
+#[test]
+fn check_renders_source_span_diagnostics() {
+    let temp = workspace();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env_remove("GITHUB_ACTIONS")
+        .args(["check", "--state", "state.json"])
+        .output()
+        .expect("run repo-contract");
+    let stdout = String::from_utf8(output.stdout).expect("utf-8");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains("error[E011]: Required approving review count is too low"));
+    assert!(stdout.contains("  --> contract.yml:9:7"));
+    assert!(stdout.contains(" 9 |       required_approving_review_count: 2"));
+    assert!(stdout.contains("expected 2, got 1 on main"));
+    assert!(stdout.contains("error[E001]: Missing required file"));
+    assert!(stdout.contains("  --> contract.rust.yml:3:5"));
+    assert!(stdout.contains("= help: Create Cargo.toml or change severity to \"warning\""));
+}
+
+#[test]
+fn validation_reports_semantic_lint_issues() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    let path = temp.path().join("contract.yml");
+    std::fs::write(
+        &path,
+        r#"version: "1.0"
+branch_protection:
+  branches: ["release/[0-9"]
+  rules:
+    required_pull_request_reviews:
+      enabled: false
+      required_approving_review_count: 2
+    required_status_checks:
+      checks:
+        - context: "ci"
+        - context: "ci"
+required_files:
+  - path: "README.md"
+  - pattern: "^LICENSE("
+  - path: "README.md"
+  - path: "docs/**/[a.md"
+"#,
+    )
+    .expect("write");
+
+    let report = validate_contract_file(&path).expect("validate");
+
+    assert!(!report.valid);
+    let pointers = report
+        .errors
+        .iter()
+        .map(|issue| issue.instance_path.as_deref().unwrap_or(""))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pointers,
+        vec![
+            "/branch_protection/branches/0",
+            "/branch_protection/rules/required_pull_request_reviews/required_approving_review_count",
+            "/branch_protection/rules/required_status_checks/checks/1/context",
+            "/required_files/1/pattern",
+            "/required_files/2/path",
+            "/required_files/3/path",
+        ]
+    );
+    let duplicate = &report.errors[4];
+    assert!(duplicate
+        .message
+        .contains("already listed at /required_files/0"));
+    let location = duplicate.location.as_ref().expect("location");
+    assert_eq!((location.line, location.column), (15, 5));
+}
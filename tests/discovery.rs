@@ -0,0 +1,93 @@
+use std::path::Path;
+use std::process::Command;
+
+fn run(directory: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(directory)
+        .env_remove("GITHUB_ACTIONS")
+        .args(args)
+        .output()
+        .expect("run repo-contract")
+}
+
+#[test]
+fn discovers_contract_upward_until_repository_root() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    let root = temp.path().join("repo");
+    let nested = root.join("src/module");
+    std::fs::create_dir_all(&nested).expect("create nested");
+    std::fs::create_dir_all(root.join(".git")).expect("create .git");
+    std::fs::create_dir_all(root.join(".github")).expect("create .github");
+    std::fs::write(
+        root.join(".github/contract.yml"),
+        "version: \"1.0\"\nrequired_files: []\n",
+    )
+    .expect("write contract");
+
+    let output = run(&nested, &["validate"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8(output.stdout)
+        .expect("utf-8")
+        .contains("✓ ../../.github/contract.yml: Valid"));
+
+    std::fs::remove_file(root.join(".github/contract.yml")).expect("remove contract");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        "version: \"1.0\"\nrequired_files: []\n",
+    )
+    .expect("write outer contract");
+    let output = run(&nested, &["validate"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn toml_contract_resolves_toml_profile() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.toml"),
+        "version = \"1.0\"\nprofile = \"rust\"\n\n[[required_files]]\npath = \"README.md\"\n",
+    )
+    .expect("write contract");
+    std::fs::write(
+        temp.path().join("contract.rust.toml"),
+        "version = \"1.0\"\n\n[[required_files]]\npath = \"Cargo.toml\"\nseverity = \"warning\"\n",
+    )
+    .expect("write profile");
+    std::fs::write(temp.path().join("README.md"), "# readme\n").expect("write readme");
+
+    let output = run(
+        temp.path(),
+        &["validate", "--merged", "--print-effective", "json"],
+    );
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["required_files"][1]["path"], "Cargo.toml");
+
+    let output = run(
+        temp.path(),
+        &["check", "--rules", "required_files", "--format", "json"],
+    );
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["summary"]["warning"], 1);
+}
+
+#[test]
+fn json_contract_is_validated_against_schema() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.json"),
+        r#"{"version": "1.0", "required_files": [{"path": "README.md", "severity": "fatal"}]}"#,
+    )
+    .expect("write contract");
+
+    let output = run(temp.path(), &["validate", "--format", "json"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["files"][0]["path"], "contract.json");
+    assert_eq!(
+        json["files"][0]["errors"][0]["instance_path"],
+        "/required_files/0/severity"
+    );
+}
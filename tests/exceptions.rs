@@ -0,0 +1,178 @@
+use repo_contract::result_schema_json;
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+branch_protection:
+  branches: ["main", "release/*"]
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 2
+required_files:
+  - path: "README.md"
+  - path: "SECURITY.md"
+  - path: "CODEOWNERS"
+    severity: "warning"
+exceptions:
+  - rule: required_files
+    target: "SECURITY.md"
+    reason: "Legacy repository, security policy lands in Q3"
+    owner: "@alice"
+    expires: "2999-09-30"
+  - rule: required_files
+    target: "CODEOWNERS"
+    reason: "Ownership is being reorganised"
+    owner: "@bob"
+    expires: "2000-01-01"
+  - rule: branch_protection
+    target: "release/*"
+    path: "required_pull_request_reviews.required_approving_review_count"
+    reason: "Release branches are cut by automation"
+    owner: "@acme/release"
+    expires: "2999-12-31"
+"#;
+
+const STATE: &str = r#"{
+  "version": 1,
+  "repository": "owner/repo",
+  "branches": ["main", "release/1.0"],
+  "protections": {
+    "main": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 2
+      }
+    },
+    "release/1.0": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1
+      }
+    }
+  }
+}"#;
+
+fn run_check(format: &str) -> std::process::Output {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+    Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env_remove("GITHUB_STEP_SUMMARY")
+        .env_remove("GITHUB_OUTPUT")
+        .args(["check", "--state", "state.json", "--format", format])
+        .output()
+        .expect("run repo-contract")
+}
+
+#[test]
+fn waived_findings_do_not_count_and_expired_waivers_become_errors() {
+    let output = run_check("json");
+
+    assert_eq!(output.status.code(), Some(1));
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(
+        result["summary"],
+        serde_json::json!({ "error": 1, "warning": 0, "info": 0, "waived": 2 })
+    );
+    let failures = result["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .filter(|finding| finding["passed"] == false)
+        .collect::<Vec<_>>();
+    let release = failures
+        .iter()
+        .find(|finding| finding["target"] == "release/1.0")
+        .expect("release finding");
+    assert_eq!(release["waiver"]["owner"], "@acme/release");
+    assert_eq!(release["waiver"]["expired"], false);
+    let codeowners = failures
+        .iter()
+        .find(|finding| finding["target"] == "CODEOWNERS")
+        .expect("codeowners finding");
+    assert_eq!(codeowners["severity"], "error");
+    assert_eq!(codeowners["waiver"]["expired"], true);
+
+    let schema: serde_json::Value =
+        serde_json::from_str(result_schema_json()).expect("result schema");
+    let compiled = jsonschema::JSONSchema::compile(&schema).expect("compile schema");
+    assert!(compiled.is_valid(&result));
+}
+
+#[test]
+fn waived_findings_stay_visible_in_every_format() {
+    let human = String::from_utf8_lossy(&run_check("human").stdout).to_string();
+    assert!(human.contains(
+        "⊘ SECURITY.md: Not found (error) [waived until 2999-09-30 by @alice: Legacy repository, security policy lands in Q3]"
+    ));
+    assert!(human.contains("✗ CODEOWNERS: Not found (error) [waiver expired on 2000-01-01 (@bob)]"));
+    assert!(human.contains("Summary: 1 error, 0 warning, 0 info, 2 waived"));
+
+    let sarif: serde_json::Value =
+        serde_json::from_slice(&run_check("sarif").stdout).expect("sarif output");
+    let results = sarif["runs"][0]["results"].as_array().expect("results");
+    assert_eq!(results.len(), 3);
+    let suppressed = results
+        .iter()
+        .filter(|result| result.get("suppressions").is_some())
+        .count();
+    assert_eq!(suppressed, 2);
+
+    let junit = String::from_utf8_lossy(&run_check("junit").stdout).to_string();
+    assert!(junit.contains(
+        "<skipped message=\"Required file not found: SECURITY.md [waived until 2999-09-30"
+    ));
+    assert!(
+        junit.contains("<failure message=\"Required file not found: CODEOWNERS [waiver expired")
+    );
+
+    let markdown = String::from_utf8_lossy(&run_check("markdown").stdout).to_string();
+    assert!(markdown.contains("### Waived (2)"));
+    assert!(markdown.contains(
+        "| `required_files` | `SECURITY.md` | Legacy repository, security policy lands in Q3 | @alice | 2999-09-30 |"
+    ));
+
+    let github = String::from_utf8_lossy(&run_check("github").stdout).to_string();
+    assert!(github.contains("::notice "));
+    assert!(github.contains("Summary: 1 error, 0 warning, 0 info, 2 waived"));
+
+    let html = String::from_utf8_lossy(&run_check("html").stdout).to_string();
+    assert!(html.contains("<span class=\"badge waived\">WAIVED</span>"));
+}
+
+#[test]
+fn validate_requires_reason_owner_and_expiry() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+exceptions:
+  - rule: required_files
+    target: "SECURITY.md"
+    owner: "@alice"
+"#,
+    )
+    .expect("write contract");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["validate", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    let messages = report["files"][0]["errors"]
+        .as_array()
+        .expect("errors")
+        .iter()
+        .map(|error| error["message"].as_str().unwrap_or_default().to_string())
+        .collect::<Vec<_>>();
+    assert!(messages
+        .iter()
+        .any(|message| message.contains("\"reason\"")));
+    assert!(messages
+        .iter()
+        .any(|message| message.contains("\"expires\"")));
+}
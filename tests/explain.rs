@@ -0,0 +1,189 @@
+use std::process::Command;
+
+#[test]
+fn explain_reports_origin_of_each_effective_field() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+profile: rust
+branch_protection:
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 2
+required_files:
+  - path: "README.md"
+"#,
+    )
+    .expect("write contract");
+    std::fs::write(
+        temp.path().join("contract.rust.yml"),
+        r#"version: "1.0"
+branch_protection:
+  branches: ["main", "release/*"]
+required_files:
+  - path: "Cargo.toml"
+    severity: warning
+"#,
+    )
+    .expect("write profile");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["explain", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(0));
+    let fields: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).expect("json");
+    let origin = |path: &str| {
+        let field = fields
+            .iter()
+            .find(|field| field["path"] == path)
+            .unwrap_or_else(|| panic!("{path} is explained"));
+        field.get("origin").map(|origin| {
+            format!(
+                "{}:{}",
+                origin["file"]
+                    .as_str()
+                    .expect("file")
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or(""),
+                origin["line"]
+            )
+        })
+    };
+
+    assert_eq!(origin("version").as_deref(), Some("contract.yml:1"));
+    assert_eq!(
+        origin("branch_protection.branches").as_deref(),
+        Some("contract.rust.yml:3")
+    );
+    assert_eq!(
+        origin(
+            "branch_protection.rules.required_pull_request_reviews.required_approving_review_count"
+        ),
+        None
+    );
+    assert_eq!(
+        origin("required_files[0].path").as_deref(),
+        Some("contract.yml:8")
+    );
+    assert_eq!(
+        origin("required_files[1].severity").as_deref(),
+        Some("contract.rust.yml:6")
+    );
+    assert_eq!(origin("required_files[1].case_insensitive"), None);
+}
+
+#[test]
+fn explain_tracks_presets_conditional_blocks_and_variables() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+profile: rust
+vars:
+  REVIEWS:
+    required_approving_review_count: 2
+tiers:
+  regulated:
+    branch_protection:
+      rules:
+        enforce_admins: true
+    required_files:
+      - path: "SECURITY.md"
+metadata:
+  tier: regulated
+branch_protection:
+  rules:
+    required_pull_request_reviews: ${REVIEWS}
+required_files:
+  - path: "README.md"
+when:
+  - tier: regulated
+    branch_protection:
+      rules:
+        required_signatures: true
+"#,
+    )
+    .expect("write contract");
+    std::fs::write(
+        temp.path().join("contract.rust.yml"),
+        r#"version: "1.0"
+required_files:
+  - path: "Cargo.toml"
+"#,
+    )
+    .expect("write profile");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["explain", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(0));
+    let fields: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).expect("json");
+    let origin = |path: &str| {
+        let field = fields
+            .iter()
+            .find(|field| field["path"] == path)
+            .unwrap_or_else(|| panic!("{path} is explained"));
+        let location = field.get("origin").map(|origin| {
+            format!(
+                "{}:{}",
+                origin["file"]
+                    .as_str()
+                    .expect("file")
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or(""),
+                origin["line"]
+            )
+        });
+        (location, field["tier_preset"].as_str().map(str::to_string))
+    };
+
+    assert_eq!(
+        origin("branch_protection.rules.enforce_admins"),
+        (
+            Some("contract.yml:10".to_string()),
+            Some("regulated".to_string())
+        )
+    );
+    assert_eq!(
+        origin("branch_protection.rules.required_signatures"),
+        (Some("contract.yml:24".to_string()), None)
+    );
+    assert_eq!(
+        origin(
+            "branch_protection.rules.required_pull_request_reviews.required_approving_review_count"
+        ),
+        (Some("contract.yml:5".to_string()), None)
+    );
+    assert_eq!(
+        origin("branch_protection.rules.required_pull_request_reviews.dismiss_stale_reviews"),
+        (None, None)
+    );
+    assert_eq!(
+        origin("branch_protection.rules.allow_deletions"),
+        (None, None)
+    );
+    assert_eq!(
+        origin("required_files[0].path"),
+        (Some("contract.yml:19".to_string()), None)
+    );
+    assert_eq!(
+        origin("required_files[1].path"),
+        (Some("contract.rust.yml:3".to_string()), None)
+    );
+    assert_eq!(
+        origin("required_files[2].path"),
+        (
+            Some("contract.yml:12".to_string()),
+            Some("regulated".to_string())
+        )
+    );
+}
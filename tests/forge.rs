@@ -0,0 +1,265 @@
+mod support;
+
+use repo_contract::{
+    check_branch_protection, check_remote_required_files, BranchProtection, BranchProtectionRules,
+    Forge, GiteaClient, GitlabClient, RequiredFile, Severity,
+};
+use support::{StubRequest, StubResponse, StubServer};
+
+fn route(request: &StubRequest) -> &str {
+    request
+        .path
+        .split_once('?')
+        .map(|(path, _)| path)
+        .unwrap_or(&request.path)
+}
+
+#[test]
+fn maps_gitlab_protected_branch_and_project_settings() {
+    let server = StubServer::start(|request| match route(request) {
+        "/projects/group%2Fsub%2Frepo/protected_branches" => StubResponse::json(
+            200,
+            r#"[{"name": "release/*", "allow_force_push": false, "code_owner_approval_required": true}]"#,
+        ),
+        "/projects/group%2Fsub%2Frepo" => StubResponse::json(
+            200,
+            r#"{"default_branch": "main", "merge_method": "ff", "only_allow_merge_if_pipeline_succeeds": true, "only_allow_merge_if_all_discussions_are_resolved": true}"#,
+        ),
+        "/projects/group%2Fsub%2Frepo/approval_rules" => StubResponse::json(
+            200,
+            r#"[{"approvals_required": 2, "protected_branches": [{"name": "release/*"}]}]"#,
+        ),
+        "/projects/group%2Fsub%2Frepo/approvals" => {
+            StubResponse::json(200, r#"{"reset_approvals_on_push": true}"#)
+        }
+        "/projects/group%2Fsub%2Frepo/external_status_checks" => {
+            StubResponse::json(403, r#"{"message": "403 Forbidden"}"#)
+        }
+        "/projects/group%2Fsub%2Frepo/push_rule" => {
+            StubResponse::json(200, r#"{"reject_unsigned_commits": true}"#)
+        }
+        _ => StubResponse::json(404, r#"{"message": "404 Not Found"}"#),
+    });
+    let client = GitlabClient::with_base_url(Some("token".to_string()), server.base_url.clone());
+
+    let rules = client
+        .get_branch_protection("group/sub/repo", "release/1.0")
+        .expect("protection")
+        .expect("protected");
+
+    assert!(rules.required_pull_request_reviews.enabled);
+    assert_eq!(
+        rules
+            .required_pull_request_reviews
+            .required_approving_review_count,
+        2
+    );
+    assert!(rules.required_pull_request_reviews.dismiss_stale_reviews);
+    assert!(
+        rules
+            .required_pull_request_reviews
+            .require_code_owner_reviews
+    );
+    assert!(rules.required_status_checks.enabled);
+    assert!(rules.required_status_checks.strict);
+    assert!(rules.required_linear_history);
+    assert!(rules.required_conversation_resolution);
+    assert!(rules.required_signatures);
+    assert!(!rules.allow_force_pushes);
+    assert!(client
+        .get_branch_protection("group/sub/repo", "feature/x")
+        .expect("protection")
+        .is_none());
+    assert!(server
+        .requests()
+        .iter()
+        .all(|request| request.header("PRIVATE-TOKEN") == Some("token")));
+}
+
+#[test]
+fn gitlab_rate_limited_feature_endpoint_is_an_error() {
+    let server = StubServer::start(|request| match route(request) {
+        "/projects/group%2Frepo/protected_branches" => {
+            StubResponse::json(200, r#"[{"name": "main"}]"#)
+        }
+        "/projects/group%2Frepo" => StubResponse::json(200, r#"{"default_branch": "main"}"#),
+        "/projects/group%2Frepo/approval_rules" => {
+            StubResponse::json(403, r#"{"message": "rate limited"}"#)
+                .with_header("Retry-After", "0")
+        }
+        _ => StubResponse::json(404, r#"{"message": "404 Not Found"}"#),
+    });
+    let client = GitlabClient::with_base_url(Some("token".to_string()), server.base_url.clone());
+
+    let error = client
+        .get_branch_protection("group/repo", "main")
+        .expect_err("rate limited");
+
+    assert!(error.to_string().contains("rate limit"), "{error}");
+}
+
+#[test]
+fn gitlab_fetches_protections_once_and_skips_unsupported_fields() {
+    let server = StubServer::start(|request| match route(request) {
+        "/projects/group%2Frepo/repository/branches" => StubResponse::json(
+            200,
+            r#"[{"name": "main"}, {"name": "release/1.0"}, {"name": "release/2.0"}]"#,
+        ),
+        "/projects/group%2Frepo/protected_branches" => StubResponse::json(
+            200,
+            r#"[{"name": "release/*", "allow_force_push": false, "code_owner_approval_required": false}]"#,
+        ),
+        "/projects/group%2Frepo" => StubResponse::json(200, r#"{"default_branch": "main"}"#),
+        _ => StubResponse::json(404, r#"{"message": "404 Not Found"}"#),
+    });
+    let client = GitlabClient::with_base_url(None, server.base_url.clone());
+    let config = BranchProtection {
+        branches: vec!["release/*".to_string()],
+        rules: BranchProtectionRules {
+            enforce_admins: true,
+            allow_deletions: true,
+            required_signatures: true,
+            ..BranchProtectionRules::default()
+        },
+        severity: Default::default(),
+        branch_severity: Vec::new(),
+        locations: Default::default(),
+    };
+
+    let reports = check_branch_protection(&client, "group/repo", &config).expect("check");
+
+    assert_eq!(reports.len(), 2);
+    for report in &reports {
+        let paths = report
+            .details
+            .iter()
+            .map(|detail| detail.path.as_str())
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&"required_signatures"));
+        assert!(!paths.contains(&"enforce_admins"));
+        assert!(!paths.contains(&"allow_deletions"));
+    }
+    let fetched = |path: &str| {
+        server
+            .requests()
+            .iter()
+            .filter(|request| route(request) == path)
+            .count()
+    };
+    assert_eq!(fetched("/projects/group%2Frepo/protected_branches"), 1);
+    assert_eq!(fetched("/projects/group%2Frepo"), 1);
+}
+
+#[test]
+fn maps_gitea_branch_protection_rules() {
+    let server = StubServer::start(|request| match route(request) {
+        "/repos/owner/repo/branch_protections" => StubResponse::json(
+            200,
+            r#"[{"rule_name": "main", "required_approvals": 1, "enable_status_check": true, "status_check_contexts": ["ci/build"], "block_on_outdated_branch": true, "apply_to_admins": true}]"#,
+        ),
+        _ => StubResponse::json(404, r#"{"message": "not found"}"#),
+    });
+    let client = GiteaClient::with_base_url(Some("token".to_string()), server.base_url.clone());
+
+    let rules = client
+        .get_branch_protection("owner/repo", "main")
+        .expect("protection")
+        .expect("protected");
+
+    assert_eq!(
+        rules
+            .required_pull_request_reviews
+            .required_approving_review_count,
+        1
+    );
+    assert!(rules.required_status_checks.strict);
+    assert_eq!(rules.required_status_checks.checks[0].context, "ci/build");
+    assert!(rules.enforce_admins);
+    assert_eq!(
+        server.requests()[0].header("Authorization"),
+        Some("token token")
+    );
+}
+
+#[test]
+fn checks_required_files_against_remote_tree() {
+    let server = StubServer::start(|request| match route(request) {
+        "/repos/owner/repo" => StubResponse::json(200, r#"{"default_branch": "main"}"#),
+        "/repos/owner/repo/git/trees/main" => StubResponse::json(
+            200,
+            r#"{"tree": [{"path": "README.md", "type": "blob"}, {"path": ".github/workflows/ci.yml", "type": "blob"}], "truncated": false}"#,
+        ),
+        _ => StubResponse::json(404, r#"{"message": "not found"}"#),
+    });
+    let client = GiteaClient::with_base_url(None, server.base_url.clone());
+    let required = |path: &str| RequiredFile {
+        path: Some(path.to_string()),
+        pattern: None,
+        description: None,
+        alternatives: Vec::new(),
+        severity: Severity::Error,
+        case_insensitive: false,
+        location: None,
+    };
+
+    let report = check_remote_required_files(
+        &client,
+        "owner/repo",
+        &[
+            required("README.md"),
+            required(".github/workflows"),
+            required("LICENSE"),
+        ],
+    )
+    .expect("check");
+
+    let exists = report
+        .checks
+        .iter()
+        .map(|check| check.exists)
+        .collect::<Vec<_>>();
+    assert_eq!(exists, vec![true, true, false]);
+    assert_eq!(report.summary.error, 1);
+}
+
+#[test]
+fn check_reads_remote_files_only_when_asked() {
+    let server = StubServer::start(|request| match route(request) {
+        "/repos/owner/repo" => StubResponse::json(200, r#"{"default_branch": "main"}"#),
+        "/repos/owner/repo/git/trees/main" => StubResponse::json(
+            200,
+            r#"{"tree": [{"path": "LICENSE", "type": "blob"}], "truncated": false}"#,
+        ),
+        _ => StubResponse::json(404, r#"{"message": "not found"}"#),
+    });
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        "version: \"1.0\"\nrequired_files:\n  - path: \"README.md\"\n",
+    )
+    .expect("write contract");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+    let check = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+            .current_dir(temp.path())
+            .env("GITHUB_TOKEN", "token")
+            .env("GITHUB_API_URL", &server.base_url)
+            .env_remove("GITHUB_ACTIONS")
+            .args([
+                "--no-cache",
+                "check",
+                "--rules",
+                "required_files",
+                "--remote",
+                "owner/repo",
+            ])
+            .args(extra)
+            .output()
+            .expect("run repo-contract")
+            .status
+            .code()
+    };
+
+    assert_eq!(check(&[]), Some(0));
+    assert_eq!(check(&["--remote-files"]), Some(1));
+}
@@ -0,0 +1,109 @@
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+profile: "rust"
+
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_pull_request_reviews:
+      enabled: true
+      required_approving_review_count: 2
+required_files:
+  - path: "README.md"
+  - path: "SECURITY.md"
+    severity: "warning"
+"#;
+
+const PROFILE: &str = r#"version: "1.0"
+required_files:
+  - path: "Cargo.toml"
+"#;
+
+const STATE: &str = r#"{
+  "version": 1,
+  "repository": "owner/repo",
+  "branches": ["main"],
+  "protections": {
+    "main": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1
+      }
+    }
+  }
+}"#;
+
+#[test]
+fn github_actions_environment_emits_annotations_and_outputs() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("contract.rust.yml"), PROFILE).expect("write profile");
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+    let outputs = temp.path().join("github_output");
+    let step_summary = temp.path().join("step_summary.md");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env("GITHUB_ACTIONS", "true")
+        .env("GITHUB_OUTPUT", &outputs)
+        .env("GITHUB_STEP_SUMMARY", &step_summary)
+        .args(["check", "--state", "state.json"])
+        .output()
+        .expect("run repo-contract");
+    let stdout = String::from_utf8(output.stdout).expect("utf-8");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains(
+        "::error file=contract.yml,line=9,col=7,title=E011 review-count-too-low::[main] "
+    ));
+    assert!(stdout.contains(
+        "::warning file=contract.yml,line=12,col=5,title=E001 missing-required-file::File not found: SECURITY.md (severity: warning)"
+    ));
+    assert!(stdout.contains(
+        "::error file=contract.rust.yml,line=3,col=5,title=E001 missing-required-file::Missing required file: Cargo.toml"
+    ));
+
+    let outputs = std::fs::read_to_string(outputs).expect("read outputs");
+    assert!(outputs.contains("valid=false\n"));
+    assert!(outputs.contains("error-count=2\n"));
+    assert!(outputs.contains("warning-count=1\n"));
+    let result_json = outputs
+        .lines()
+        .find_map(|line| line.strip_prefix("result-json="))
+        .expect("result-json output");
+    let result: serde_json::Value = serde_json::from_str(result_json).expect("result json");
+    assert_eq!(result["summary"]["error"], 2);
+
+    let step_summary = std::fs::read_to_string(step_summary).expect("read summary");
+    assert!(step_summary.contains("## 🔴 Contract Violation Detected"));
+    assert!(step_summary.contains("### Errors (2)"));
+    assert!(step_summary.contains("| `required_files` | `Cargo.toml` | File not found |"));
+}
+
+#[test]
+fn github_format_reports_valid_when_only_warnings() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        "version: \"1.0\"\nrequired_files:\n  - path: \"SECURITY.md\"\n    severity: \"info\"\n",
+    )
+    .expect("write contract");
+    let outputs = temp.path().join("github_output");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env_remove("GITHUB_ACTIONS")
+        .env_remove("GITHUB_STEP_SUMMARY")
+        .env("GITHUB_OUTPUT", &outputs)
+        .args(["check", "--rules", "required_files", "--format", "github"])
+        .output()
+        .expect("run repo-contract");
+    let stdout = String::from_utf8(output.stdout).expect("utf-8");
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout.starts_with("::notice file=contract.yml,line=3,col=5,"));
+    let outputs = std::fs::read_to_string(outputs).expect("read outputs");
+    assert!(outputs.starts_with("valid=true\nerror-count=0\nwarning-count=0\ninfo-count=1\n"));
+}
@@ -0,0 +1,193 @@
+mod support;
+
+use repo_contract::{
+    check_branch_protection, BranchProtection, BranchProtectionRules, Forge, GithubClient,
+    ResponseCache,
+};
+use support::{StubResponse, StubServer};
+
+const BRANCHES: &str = r#"[{"name": "main"}, {"name": "release/1.0"}]"#;
+
+#[test]
+fn revalidates_cached_responses_with_etag() {
+    let server = StubServer::start(|request| {
+        if request.header("If-None-Match") == Some("\"v1\"") {
+            StubResponse::json(304, "")
+        } else {
+            StubResponse::json(200, BRANCHES).with_header("ETag", "\"v1\"")
+        }
+    });
+    let cache_dir = tempfile::TempDir::new().expect("tempdir");
+    let client = || {
+        GithubClient::with_base_url(Some("token".to_string()), server.base_url.clone())
+            .with_cache(ResponseCache::new(cache_dir.path().to_path_buf()))
+    };
+
+    let first = client().list_branches("owner/repo").expect("first");
+    let second = client().list_branches("owner/repo").expect("second");
+
+    assert_eq!(first, vec!["main".to_string(), "release/1.0".to_string()]);
+    assert_eq!(first, second);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].header("If-None-Match"), None);
+    assert_eq!(requests[1].header("If-None-Match"), Some("\"v1\""));
+}
+
+#[test]
+fn cache_entries_are_scoped_to_token() {
+    let server = StubServer::start(|request| {
+        if request.header("If-None-Match").is_some() {
+            StubResponse::json(304, "")
+        } else {
+            StubResponse::json(200, BRANCHES).with_header("ETag", "\"v1\"")
+        }
+    });
+    let cache_dir = tempfile::TempDir::new().expect("tempdir");
+    let client = |token: &str| {
+        GithubClient::with_base_url(Some(token.to_string()), server.base_url.clone())
+            .with_cache(ResponseCache::new(cache_dir.path().to_path_buf()))
+    };
+
+    client("first").list_branches("owner/repo").expect("first");
+    client("second")
+        .list_branches("owner/repo")
+        .expect("second");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].header("If-None-Match"), None);
+}
+
+#[test]
+fn unwritable_cache_does_not_fail_requests() {
+    let server =
+        StubServer::start(|_| StubResponse::json(200, BRANCHES).with_header("ETag", "\"v1\""));
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    let not_a_directory = temp.path().join("cache");
+    std::fs::write(&not_a_directory, "").expect("write file");
+    let client = GithubClient::with_base_url(None, server.base_url.clone())
+        .with_cache(ResponseCache::new(not_a_directory));
+
+    let branches = client.list_branches("owner/repo").expect("branches");
+
+    assert_eq!(
+        branches,
+        vec!["main".to_string(), "release/1.0".to_string()]
+    );
+}
+
+#[test]
+fn client_without_cache_never_sends_conditional_requests() {
+    let server =
+        StubServer::start(|_| StubResponse::json(200, BRANCHES).with_header("ETag", "\"v1\""));
+    let client = GithubClient::with_base_url(None, server.base_url.clone());
+
+    client.list_branches("owner/repo").expect("first");
+    client.list_branches("owner/repo").expect("second");
+
+    assert!(server
+        .requests()
+        .iter()
+        .all(|request| request.header("If-None-Match").is_none()));
+}
+
+const PROTECTION: &str = r#"{
+    "required_pull_request_reviews": {
+        "required_approving_review_count": 1,
+        "dismiss_stale_reviews": true,
+        "require_code_owner_reviews": false,
+        "require_last_push_approval": false
+    },
+    "required_status_checks": {"strict": true, "contexts": []}
+}"#;
+
+fn release_branches(count: usize) -> String {
+    let names = (0..count)
+        .map(|index| format!(r#"{{"name": "release/{index:02}"}}"#))
+        .collect::<Vec<_>>();
+    format!("[{}]", names.join(","))
+}
+
+#[test]
+fn fetches_protections_concurrently_in_stable_order() {
+    let branches = release_branches(12);
+    let server = StubServer::start(move |request| {
+        if request.path.starts_with("/repos/owner/repo/branches?") {
+            return StubResponse::json(200, &branches);
+        }
+        let index = request
+            .path
+            .trim_end_matches("/protection")
+            .rsplit('/')
+            .next()
+            .and_then(|value| value.parse::<u64>().ok())
+            .unwrap_or(0);
+        std::thread::sleep(std::time::Duration::from_millis(60 - index * 5));
+        if index % 2 == 0 {
+            StubResponse::json(200, PROTECTION)
+        } else {
+            StubResponse::json(404, r#"{"message": "Branch not protected"}"#)
+        }
+    });
+    let client = GithubClient::with_base_url(None, server.base_url.clone()).with_concurrency(4);
+    let config = BranchProtection {
+        branches: vec!["release/*".to_string()],
+        rules: BranchProtectionRules::default(),
+        severity: Default::default(),
+        branch_severity: Vec::new(),
+        locations: Default::default(),
+    };
+
+    let reports = check_branch_protection(&client, "owner/repo", &config).expect("check");
+
+    let targets = reports
+        .iter()
+        .map(|report| report.target.clone())
+        .collect::<Vec<_>>();
+    let expected = (0..12)
+        .map(|index| format!("release/{index:02}"))
+        .collect::<Vec<_>>();
+    assert_eq!(targets, expected);
+    for (index, report) in reports.iter().enumerate() {
+        let unprotected = report
+            .checks
+            .iter()
+            .any(|check| check.path == "branch_protection");
+        assert_eq!(unprotected, index % 2 == 1, "{}", report.target);
+    }
+}
+
+#[test]
+fn retries_after_rate_limit_response() {
+    let attempts = std::sync::atomic::AtomicUsize::new(0);
+    let server = StubServer::start(move |_| {
+        if attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+            StubResponse::json(429, r#"{"message": "rate limited"}"#)
+                .with_header("Retry-After", "0")
+        } else {
+            StubResponse::json(200, BRANCHES)
+        }
+    });
+    let client = GithubClient::with_base_url(None, server.base_url.clone());
+
+    let branches = client.list_branches("owner/repo").expect("branches");
+
+    assert_eq!(branches.len(), 2);
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn fails_when_rate_limit_reset_is_too_far_away() {
+    let server = StubServer::start(|_| {
+        StubResponse::json(403, r#"{"message": "API rate limit exceeded"}"#)
+            .with_header("X-RateLimit-Remaining", "0")
+            .with_header("X-RateLimit-Reset", "99999999999")
+    });
+    let client = GithubClient::with_base_url(None, server.base_url.clone());
+
+    let error = client
+        .list_branches("owner/repo")
+        .expect_err("rate limited");
+    assert!(error.to_string().contains("rate limit"));
+}
@@ -0,0 +1,59 @@
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 2
+required_files:
+  - path: "README.md"
+  - path: "SECURITY.md"
+    description: "Security <policy>"
+    severity: "warning"
+"#;
+
+const STATE: &str = r#"{
+  "version": 1,
+  "repository": "owner/repo",
+  "branches": ["main"],
+  "protections": {
+    "main": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1
+      }
+    }
+  }
+}"#;
+
+#[test]
+fn check_renders_standalone_html_report() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["check", "--state", "state.json", "--format", "html"])
+        .output()
+        .expect("run repo-contract");
+    let html = String::from_utf8(output.stdout).expect("utf-8");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<style>"));
+    assert!(!html.contains("<link") && !html.contains("<script"));
+    assert!(html.contains(
+        r#"<h1>Repo Contract Compliance Report <span class="badge error">FAILED</span></h1>"#
+    ));
+    assert!(html.contains("<h2>Branch Protection <code>main</code></h2>"));
+    assert!(html.contains(
+        r#"<tr><td><code>required_pull_request_reviews.required_approving_review_count</code></td><td>2</td><td>1</td><td><span class="badge error">FAIL</span></td></tr>"#
+    ));
+    assert!(html.contains(r#"<td><code>README.md</code></td><td></td><td>error</td><td><span class="badge pass">PASS</span></td>"#));
+    assert!(html.contains("<td>Security &lt;policy&gt;</td><td>warning</td><td><span class=\"badge warning\">WARNING</span></td>"));
+    assert!(html.contains("<h2>Evaluated Contract</h2>"));
+    assert!(html.contains("required_approving_review_count: 2"));
+}
@@ -0,0 +1,272 @@
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+vars:
+  approvals: 1
+  check_prefix: "ci / ${CONTRACT_REPO_NAME}"
+metadata:
+  tier: "${CONTRACT_TIER}"
+branch_protection:
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: "${approvals}"
+    required_status_checks:
+      checks:
+        - context: "${check_prefix} / test"
+        - context: "$${literal}"
+when:
+  - tier: critical
+    branch_protection:
+      rules:
+        required_pull_request_reviews:
+          required_approving_review_count: 2
+        required_signatures: true
+  - tier: [experimental]
+    required_files:
+      - path: "EXPERIMENTAL.md"
+"#;
+
+fn run(temp: &tempfile::TempDir, tier: &str, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env("CONTRACT_REPO_NAME", "widgets")
+        .env("CONTRACT_TIER", tier)
+        .args(args)
+        .output()
+        .expect("run repo-contract")
+}
+
+#[test]
+fn resolves_variables_and_tier_conditional_blocks() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+
+    let output = run(
+        &temp,
+        "critical",
+        &["validate", "--merged", "--print-effective", "json"],
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let rules = &json["branch_protection"]["rules"];
+    assert_eq!(
+        rules["required_pull_request_reviews"]["required_approving_review_count"],
+        2
+    );
+    assert_eq!(rules["required_signatures"], true);
+    assert_eq!(
+        rules["required_status_checks"]["checks"][0]["context"],
+        "ci / widgets / test"
+    );
+    assert_eq!(
+        rules["required_status_checks"]["checks"][1]["context"],
+        "${literal}"
+    );
+    assert_eq!(json["required_files"], serde_json::json!([]));
+
+    let output = run(
+        &temp,
+        "experimental",
+        &["validate", "--merged", "--print-effective", "json"],
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(
+        json["branch_protection"]["rules"]["required_pull_request_reviews"]
+            ["required_approving_review_count"],
+        1
+    );
+    assert_eq!(json["required_files"][0]["path"], "EXPERIMENTAL.md");
+}
+
+#[test]
+fn reports_undefined_variables() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        CONTRACT.replace("${check_prefix}", "${MISSING_PREFIX}"),
+    )
+    .expect("write contract");
+
+    let output = run(&temp, "standard", &["validate", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let error = &json["files"][0]["errors"][0];
+    assert_eq!(
+        error["instance_path"],
+        "/branch_protection/rules/required_status_checks/checks/0/context"
+    );
+    assert_eq!(error["message"], "Undefined variable ${MISSING_PREFIX}");
+    assert_eq!(error["location"]["line"], 13);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env("CONTRACT_TIER", "standard")
+        .env_remove("GITHUB_ACTIONS")
+        .args(["check", "--rules", "required_files"])
+        .output()
+        .expect("run repo-contract");
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn environment_values_stay_strings_unless_the_field_is_typed() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+branch_protection:
+  rules:
+    enforce_admins: "${CONTRACT_ENFORCE}"
+    required_pull_request_reviews:
+      required_approving_review_count: "${CONTRACT_APPROVALS}"
+    required_status_checks:
+      checks:
+        - context: "${CONTRACT_CHECK_NAME}"
+        - context: "${CONTRACT_ENFORCE}"
+"#,
+    )
+    .expect("write contract");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env("CONTRACT_ENFORCE", "true")
+        .env("CONTRACT_APPROVALS", "3")
+        .env("CONTRACT_CHECK_NAME", "1.10")
+        .args(["validate", "--merged", "--print-effective", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let rules = &json["branch_protection"]["rules"];
+    assert_eq!(rules["enforce_admins"], true);
+    assert_eq!(
+        rules["required_pull_request_reviews"]["required_approving_review_count"],
+        3
+    );
+    assert_eq!(
+        rules["required_status_checks"]["checks"][0]["context"],
+        "1.10"
+    );
+    assert_eq!(
+        rules["required_status_checks"]["checks"][1]["context"],
+        "true"
+    );
+}
+
+#[test]
+fn profiles_see_base_variables() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+profile: rust
+vars:
+  org: "acme"
+  approvals: 1
+"#,
+    )
+    .expect("write contract");
+    std::fs::write(
+        temp.path().join("contract.rust.yml"),
+        r#"version: "1.0"
+vars:
+  approvals: 2
+branch_protection:
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: "${approvals}"
+    required_status_checks:
+      checks:
+        - context: "${org} / cargo test"
+"#,
+    )
+    .expect("write profile");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["validate", "--merged", "--print-effective", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let rules = &json["branch_protection"]["rules"];
+    assert_eq!(
+        rules["required_pull_request_reviews"]["required_approving_review_count"],
+        2
+    );
+    assert_eq!(
+        rules["required_status_checks"]["checks"][0]["context"],
+        "acme / cargo test"
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["validate", "--with-profile", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn conditional_block_values_are_located_in_their_block() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        CONTRACT.replace(
+            "        required_signatures: true",
+            "        required_signatures: \"yes\"",
+        ),
+    )
+    .expect("write contract");
+
+    let output = run(
+        &temp,
+        "experimental",
+        &["check", "--rules", "required_files", "--format", "json"],
+    );
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["findings"][0]["target"], "EXPERIMENTAL.md");
+    assert_eq!(json["findings"][0]["location"]["line"], 24);
+
+    let output = run(&temp, "critical", &["validate", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let error = &json["files"][0]["errors"][0];
+    assert_eq!(
+        error["instance_path"],
+        "/branch_protection/rules/required_signatures"
+    );
+    assert_eq!(error["location"]["line"], 21);
+}
+
+#[test]
+fn environment_variables_without_the_prefix_stay_unresolved() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+required_files:
+  - path: "${GITHUB_TOKEN}.md"
+"#,
+    )
+    .expect("write contract");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env("GITHUB_TOKEN", "ghp_secret")
+        .args(["validate", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).expect("utf-8");
+    assert!(!stdout.contains("ghp_secret"));
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("json");
+    let error = &json["files"][0]["errors"][0];
+    assert_eq!(error["instance_path"], "/required_files/0/path");
+    assert_eq!(error["message"], "Undefined variable ${GITHUB_TOKEN}");
+}
@@ -0,0 +1,63 @@
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+required_files:
+  - path: "README.md"
+  - path: "LICENSE"
+    severity: "warning"
+    description: "License <MIT & Apache>"
+"#;
+
+fn run(args: &[&str]) -> (Option<i32>, String) {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(args)
+        .output()
+        .expect("run repo-contract");
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).expect("utf-8"),
+    )
+}
+
+#[test]
+fn warnings_are_skipped_without_strict() {
+    let (code, xml) = run(&["check", "--rules", "required_files", "--format", "junit"]);
+
+    assert_eq!(code, Some(0));
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains(
+        r#"<testsuite name="required_files" tests="2" failures="0" errors="0" skipped="1">"#
+    ));
+    assert!(xml.contains(r#"<testcase classname="required_files" name="README.md"/>"#));
+    assert!(xml.contains(r#"<skipped message="Required file not found: LICENSE"/>"#));
+}
+
+#[test]
+fn warnings_are_failures_with_strict() {
+    let (code, xml) = run(&[
+        "check",
+        "--rules",
+        "required_files",
+        "--format",
+        "junit",
+        "--strict",
+    ]);
+
+    assert_eq!(code, Some(1));
+    assert!(xml.contains(r#"failures="1" errors="0" skipped="0""#));
+    assert!(xml.contains(
+        "expected: present\nactual: missing\nseverity: warning\ndescription: License &lt;MIT &amp; Apache&gt;</failure>"
+    ));
+}
+
+#[test]
+fn diff_supports_junit() {
+    let (code, xml) = run(&["diff", "--rules", "required_files", "--format", "junit"]);
+
+    assert_eq!(code, Some(1));
+    assert!(xml.contains(r#"<testsuites name="repo-contract" tests="2""#));
+}
@@ -0,0 +1,55 @@
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+required_files:
+  - path: "README.md"
+  - path: "CONTRIBUTING.md"
+  - path: "SECURITY.md"
+    severity: "warning"
+"#;
+
+fn run(files: &[&str]) -> (Option<i32>, String) {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    for file in files {
+        std::fs::write(temp.path().join(file), "content").expect("write file");
+    }
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["check", "--rules", "required_files", "--format", "markdown"])
+        .output()
+        .expect("run repo-contract");
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).expect("utf-8"),
+    )
+}
+
+#[test]
+fn markdown_renders_violation_comment() {
+    let (code, markdown) = run(&["README.md"]);
+
+    assert_eq!(code, Some(1));
+    assert!(
+        markdown.starts_with("<!-- repo-contract:report -->\n## 🔴 Contract Violation Detected\n")
+    );
+    assert!(markdown.contains(
+        "### Errors (1)\n\n| Rule | Target | Message |\n|------|--------|---------|\n| `required_files` | `CONTRIBUTING.md` | File not found |\n"
+    ));
+    assert!(markdown.contains("### Warnings (1)"));
+    assert!(markdown.contains("<summary>📋 Full Report (JSON)</summary>"));
+    assert!(markdown.contains("\"valid\": false"));
+    assert!(markdown.trim_end().ends_with(
+        "> 🤖 This comment was generated by [Repo Contract](https://github.com/pirakansa/RepoContract)"
+    ));
+}
+
+#[test]
+fn markdown_renders_success_comment() {
+    let (code, markdown) = run(&["README.md", "CONTRIBUTING.md", "SECURITY.md"]);
+
+    assert_eq!(code, Some(0));
+    assert!(markdown.starts_with("<!-- repo-contract:report -->\n## ✅ Contract Check Passed\n"));
+    assert!(markdown.contains("| Errors | 0 |\n| Warnings | 0 |\n| Info | 0 |\n"));
+    assert!(!markdown.contains("<details>"));
+}
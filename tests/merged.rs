@@ -0,0 +1,87 @@
+use std::process::Command;
+
+fn workspace(profile: &str) -> tempfile::TempDir {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+profile: rust
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 2
+required_files:
+  - path: "README.md"
+"#,
+    )
+    .expect("write contract");
+    std::fs::write(temp.path().join("contract.rust.yml"), profile).expect("write profile");
+    temp
+}
+
+#[test]
+fn validate_merged_reports_conflicts_between_contract_and_profile() {
+    let temp = workspace(
+        r#"version: "1.0"
+branch_protection:
+  branches: ["main"]
+required_files:
+  - path: "Cargo.toml"
+  - path: "README.md"
+"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["validate", "--merged", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let files = json["files"].as_array().expect("files");
+    assert_eq!(files.len(), 3);
+    assert_eq!(files[1]["valid"], true);
+    let merged = &files[2];
+    assert_eq!(merged["path"], "contract.yml + contract.rust.yml (merged)");
+    let errors = merged["errors"].as_array().expect("errors");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["instance_path"], "/required_files/1/path");
+    assert!(errors[0]["message"]
+        .as_str()
+        .expect("message")
+        .contains("already listed at /required_files/0 in contract.yml"));
+    assert_eq!(errors[1]["instance_path"], "/branch_protection");
+}
+
+#[test]
+fn validate_merged_prints_effective_contract() {
+    let temp = workspace(
+        r#"version: "1.0"
+required_files:
+  - path: "Cargo.toml"
+"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["validate", "--merged", "--print-effective", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let paths = json["required_files"]
+        .as_array()
+        .expect("required files")
+        .iter()
+        .map(|file| file["path"].as_str().unwrap_or(""))
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["README.md", "Cargo.toml"]);
+    assert_eq!(
+        json["branch_protection"]["rules"]["required_pull_request_reviews"]
+            ["required_approving_review_count"],
+        2
+    );
+}
@@ -0,0 +1,202 @@
+mod support;
+
+use std::process::Command;
+use support::{StubRequest, StubResponse, StubServer};
+
+fn route(request: &StubRequest) -> StubResponse {
+    match request.path.split('?').next().unwrap_or_default() {
+        "/users/alice" | "/users/bob" => StubResponse::json(200, r#"{"login": "x"}"#),
+        "/repos/acme/widgets/collaborators/alice/permission" => {
+            StubResponse::json(200, r#"{"permission": "write"}"#)
+        }
+        "/repos/acme/widgets/collaborators/bob/permission" => {
+            StubResponse::json(200, r#"{"permission": "read"}"#)
+        }
+        "/orgs/acme/teams/platform" | "/orgs/acme/teams/docs" => {
+            StubResponse::json(200, r#"{"slug": "x"}"#)
+        }
+        "/repos/acme/widgets/teams" => StubResponse::json(
+            200,
+            r#"[{"slug": "platform", "permission": "push"}, {"slug": "docs", "permission": "pull"}]"#,
+        ),
+        _ => StubResponse::json(404, r#"{"message": "Not Found"}"#),
+    }
+}
+
+fn run_check(temp: &tempfile::TempDir, server: &StubServer, format: &str) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env("GITHUB_TOKEN", "token")
+        .env("GITHUB_API_URL", &server.base_url)
+        .env("GITHUB_REPOSITORY", "acme/widgets")
+        .args([
+            "--no-cache",
+            "check",
+            "--rules",
+            "metadata",
+            "--format",
+            format,
+        ])
+        .output()
+        .expect("run repo-contract")
+}
+
+#[test]
+fn check_reports_missing_and_stale_maintainers() {
+    let server = StubServer::start(route);
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+metadata:
+  maintainers:
+    - "@alice"
+    - "@bob"
+    - "@ghost"
+    - "@acme/platform"
+    - "@acme/docs"
+    - "@acme/missing"
+  last_updated: "2000-01-01"
+"#,
+    )
+    .expect("write contract");
+    std::fs::write(
+        temp.path().join(".contract.toml"),
+        "[metadata]\nmax_age_days = 365\n",
+    )
+    .expect("write config");
+
+    let output = run_check(&temp, &server, "json");
+
+    assert_eq!(output.status.code(), Some(1));
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(result["summary"]["error"], 4);
+    assert_eq!(result["summary"]["warning"], 1);
+    let failures = result["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .filter(|finding| finding["passed"] == false)
+        .map(|finding| {
+            (
+                finding["code"].as_str().unwrap_or_default(),
+                finding["target"].as_str().unwrap_or_default(),
+                finding["location"]["line"].as_u64(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        failures,
+        vec![
+            ("E031", "@bob", Some(5)),
+            ("E030", "@ghost", Some(6)),
+            ("E031", "@acme/docs", Some(8)),
+            ("E030", "@acme/missing", Some(9)),
+            ("W030", "metadata", Some(10)),
+        ]
+    );
+    let bob = result["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .find(|finding| finding["code"] == "E031" && finding["target"] == "@bob")
+        .expect("@bob write access finding");
+    assert_eq!(bob["expected"], "write");
+    assert_eq!(bob["actual"], "read");
+    let stale = result["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .find(|finding| finding["code"] == "W030")
+        .expect("last_updated finding");
+    assert_eq!(stale["expected"], "at most 365 days old");
+    assert!(stale["actual"]
+        .as_str()
+        .is_some_and(|actual| actual.ends_with(" days old")));
+    let requests = server.requests();
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|request| request.path.starts_with("/repos/acme/widgets/teams"))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn human_output_lists_metadata_checks() {
+    let server = StubServer::start(route);
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+metadata:
+  maintainers: ["@alice", "@bob"]
+"#,
+    )
+    .expect("write contract");
+
+    let output = run_check(&temp, &server, "human");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Metadata\n  ✓ @alice: exists\n  ✓ @alice: has write access\n"));
+    assert!(
+        stdout.contains("✗ Maintainer @bob has no write access to acme/widgets (permission: read)")
+    );
+    assert!(stdout.contains("error[E031]: Maintainer has no write access to the repository"));
+    assert!(stdout.contains("Summary: 1 error, 0 warning, 0 info"));
+}
+
+#[test]
+fn diff_lists_metadata_failures() {
+    let server = StubServer::start(route);
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        "version: \"1.0\"\nmetadata:\n  maintainers: [\"@alice\", \"@ghost\"]\n",
+    )
+    .expect("write contract");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env("GITHUB_TOKEN", "token")
+        .env("GITHUB_API_URL", &server.base_url)
+        .env("GITHUB_REPOSITORY", "acme/widgets")
+        .args(["--no-cache", "diff", "--rules", "metadata"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Metadata:\n  ! @ghost (maintainers)\n"
+    );
+}
+
+#[test]
+fn last_updated_is_checked_without_api_access() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        "version: \"1.0\"\nmetadata:\n  last_updated: \"2999-01-01\"\n",
+    )
+    .expect("write contract");
+    std::fs::write(
+        temp.path().join(".contract.toml"),
+        "[metadata]\nmax_age_days = 30\n",
+    )
+    .expect("write config");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env_remove("GITHUB_TOKEN")
+        .args(["check", "--rules", "metadata", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(0));
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    assert_eq!(result["findings"][0]["code"], "W030");
+    assert_eq!(result["findings"][0]["passed"], true);
+}
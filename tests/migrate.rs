@@ -0,0 +1,63 @@
+use std::process::Command;
+
+fn run(temp: &tempfile::TempDir, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(args)
+        .output()
+        .expect("run repo-contract")
+}
+
+#[test]
+fn migrate_leaves_current_contract_untouched() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    let content = "version: \"1.0\" # keep\nrequired_files: []\n";
+    std::fs::write(temp.path().join("contract.yml"), content).expect("write contract");
+
+    let output = run(&temp, &["migrate", "--write"]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).expect("utf-8"),
+        "contract.yml is already at version 1.0\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("contract.yml")).expect("read"),
+        content
+    );
+}
+
+#[test]
+fn migrate_rejects_unsupported_version() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), "version: \"2.0\"\n").expect("write");
+
+    let output = run(&temp, &["migrate"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr)
+        .expect("utf-8")
+        .contains("Unsupported contract version: 2.0"));
+}
+
+#[test]
+fn validate_and_schema_follow_declared_version() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), "version: \"2.0\"\n").expect("write");
+
+    let output = run(&temp, &["validate", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["files"][0]["errors"][0]["instance_path"], "/version");
+
+    let output = run(&temp, &["schema", "--contract-version", "1.0"]);
+    assert_eq!(output.status.code(), Some(0));
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(
+        schema["$id"],
+        "https://pirakansa.github.io/RepoContract/schemas/v1.json"
+    );
+
+    let output = run(&temp, &["schema", "--contract-version", "2.0"]);
+    assert_eq!(output.status.code(), Some(2));
+}
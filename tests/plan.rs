@@ -0,0 +1,260 @@
+mod support;
+
+use repo_contract::{
+    apply_plan, create_plan, BranchProtection, BranchProtectionRules, ContractError, ForgeKind,
+    GithubClient, GitlabClient, Label, PlanAction, PlanScope, RepositorySettings,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use support::{StubResponse, StubServer};
+
+const PROTECTION: &str = r#"{
+  "required_pull_request_reviews": {
+    "dismiss_stale_reviews": false,
+    "require_code_owner_reviews": false,
+    "require_last_push_approval": false,
+    "required_approving_review_count": 1
+  },
+  "required_status_checks": {
+    "strict": true,
+    "checks": [{"context": "ci/build", "app_id": 15368}]
+  },
+  "enforce_admins": {"enabled": false}
+}"#;
+
+#[derive(Default)]
+struct Remote {
+    protected: AtomicBool,
+    branched: AtomicBool,
+}
+
+fn contract() -> BranchProtection {
+    let mut rules = BranchProtectionRules::default();
+    rules.required_pull_request_reviews.enabled = true;
+    rules
+        .required_pull_request_reviews
+        .required_approving_review_count = 2;
+    BranchProtection {
+        branches: vec!["main".to_string(), "release/*".to_string()],
+        rules,
+        severity: Default::default(),
+        branch_severity: Vec::new(),
+        locations: Default::default(),
+    }
+}
+
+fn server(remote: Arc<Remote>) -> StubServer {
+    StubServer::start(
+        move |request| match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/repos/owner/repo/branches?per_page=100&page=1") => {
+                if remote.branched.load(Ordering::SeqCst) {
+                    StubResponse::json(
+                        200,
+                        r#"[{"name": "main"}, {"name": "release/1.0"}, {"name": "release/2.0"}]"#,
+                    )
+                } else {
+                    StubResponse::json(
+                        200,
+                        r#"[{"name": "main"}, {"name": "release/1.0"}, {"name": "feature/x"}]"#,
+                    )
+                }
+            }
+            ("GET", "/repos/owner/repo/branches/main/protection") => {
+                StubResponse::json(200, PROTECTION)
+            }
+            ("GET", "/repos/owner/repo/branches/release/1.0/protection")
+                if remote.protected.load(Ordering::SeqCst) =>
+            {
+                StubResponse::json(200, PROTECTION)
+            }
+            ("GET", "/repos/owner/repo") => StubResponse::json(
+                200,
+                r#"{"default_branch": "main", "allow_merge_commit": true, "delete_branch_on_merge": false}"#,
+            ),
+            ("GET", "/repos/owner/repo/labels?per_page=100&page=1") => StubResponse::json(
+                200,
+                r#"[{"name": "bug", "color": "d73a4a", "description": "Something isn't working"}]"#,
+            ),
+            ("PUT", _) | ("PATCH", _) | ("POST", _) => StubResponse::json(200, "{}"),
+            ("DELETE", _) => StubResponse::json(204, ""),
+            _ => StubResponse::json(404, r#"{"message": "Not Found"}"#),
+        },
+    )
+}
+
+fn branch_scope(config: &BranchProtection) -> PlanScope<'_> {
+    PlanScope {
+        branch_protection: Some(config),
+        ..PlanScope::default()
+    }
+}
+
+#[test]
+fn plan_lists_changes_and_apply_overlays_only_mismatched_fields() {
+    let server = server(Arc::default());
+    let client = GithubClient::with_base_url(None, server.base_url.clone());
+    let config = contract();
+
+    let plan = create_plan(&client, "owner/repo", branch_scope(&config)).expect("plan");
+
+    assert_eq!(plan.targets, vec!["main", "release/1.0"]);
+    let changes = plan
+        .changes
+        .iter()
+        .map(|change| (change.target.as_str(), change.action))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        vec![
+            ("main", PlanAction::Update),
+            ("release/1.0", PlanAction::Create)
+        ]
+    );
+
+    let applied = apply_plan(&client, &plan).expect("apply");
+    let applied = applied
+        .iter()
+        .map(|change| change.target.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(applied, vec!["main", "release/1.0"]);
+    let puts = server
+        .requests()
+        .into_iter()
+        .filter(|request| request.method == "PUT")
+        .collect::<Vec<_>>();
+    assert_eq!(puts[0].path, "/repos/owner/repo/branches/main/protection");
+    let body: serde_json::Value = serde_json::from_str(&puts[0].body).expect("json body");
+    assert_eq!(
+        body["required_pull_request_reviews"]["required_approving_review_count"],
+        2
+    );
+    assert_eq!(
+        body["required_status_checks"]["checks"],
+        serde_json::json!([{ "context": "ci/build", "app_id": 15368 }])
+    );
+    assert_eq!(body["enforce_admins"], false);
+    assert_eq!(body["restrictions"], serde_json::Value::Null);
+}
+
+#[test]
+fn apply_refuses_stale_plan() {
+    let remote = Arc::new(Remote::default());
+    let server = server(remote.clone());
+    let client = GithubClient::with_base_url(None, server.base_url.clone());
+    let config = contract();
+    let plan = create_plan(&client, "owner/repo", branch_scope(&config)).expect("plan");
+
+    remote.protected.store(true, Ordering::SeqCst);
+    let error = apply_plan(&client, &plan).expect_err("stale");
+
+    assert!(matches!(error, ContractError::StalePlan(_)));
+    assert!(server
+        .requests()
+        .iter()
+        .all(|request| request.method == "GET"));
+}
+
+#[test]
+fn apply_refuses_plan_when_new_branch_matches() {
+    let remote = Arc::new(Remote::default());
+    let server = server(remote.clone());
+    let client = GithubClient::with_base_url(None, server.base_url.clone());
+    let config = contract();
+    let plan = create_plan(&client, "owner/repo", branch_scope(&config)).expect("plan");
+
+    remote.branched.store(true, Ordering::SeqCst);
+    let error = apply_plan(&client, &plan).expect_err("stale");
+
+    assert!(matches!(error, ContractError::StalePlan(_)));
+}
+
+#[test]
+fn plan_covers_settings_and_labels() {
+    let server = server(Arc::default());
+    let client = GithubClient::with_base_url(None, server.base_url.clone());
+    let settings = RepositorySettings {
+        allow_merge_commit: Some(false),
+        delete_branch_on_merge: Some(false),
+        ..RepositorySettings::default()
+    };
+    let labels = vec![
+        Label {
+            name: "bug".to_string(),
+            color: Some("#D73A4A".to_string()),
+            description: Some("Broken behaviour".to_string()),
+        },
+        Label {
+            name: "security".to_string(),
+            color: Some("b60205".to_string()),
+            description: None,
+        },
+    ];
+
+    let plan = create_plan(
+        &client,
+        "owner/repo",
+        PlanScope {
+            branch_protection: None,
+            settings: Some(&settings),
+            labels: &labels,
+        },
+    )
+    .expect("plan");
+
+    let changes = plan
+        .changes
+        .iter()
+        .map(|change| (change.rule.as_str(), change.target.as_str(), change.action))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        vec![
+            ("settings", "owner/repo", PlanAction::Update),
+            ("labels", "bug", PlanAction::Update),
+            ("labels", "security", PlanAction::Create),
+        ]
+    );
+
+    apply_plan(&client, &plan).expect("apply");
+    let writes = server
+        .requests()
+        .into_iter()
+        .filter(|request| request.method != "GET")
+        .map(|request| {
+            let body: serde_json::Value = serde_json::from_str(&request.body).expect("json body");
+            (request.method, request.path, body)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        writes,
+        vec![
+            (
+                "PATCH".to_string(),
+                "/repos/owner/repo".to_string(),
+                serde_json::json!({ "allow_merge_commit": false })
+            ),
+            (
+                "PATCH".to_string(),
+                "/repos/owner/repo/labels/bug".to_string(),
+                serde_json::json!({ "name": "bug", "description": "Broken behaviour" })
+            ),
+            (
+                "POST".to_string(),
+                "/repos/owner/repo/labels".to_string(),
+                serde_json::json!({ "name": "security", "color": "b60205" })
+            ),
+        ]
+    );
+}
+
+#[test]
+fn plan_rejects_forges_without_write_support() {
+    let client = GitlabClient::with_base_url(None, "http://127.0.0.1:9".to_string());
+    let config = contract();
+
+    let error = create_plan(&client, "group/repo", branch_scope(&config)).expect_err("gitlab");
+
+    assert!(matches!(error, ContractError::ForgeApi(_)));
+    assert!(!ForgeKind::Gitlab.supports_apply());
+    assert!(!ForgeKind::Gitea.supports_apply());
+}
@@ -0,0 +1,131 @@
+mod support;
+
+use repo_contract::{
+    publish_pull_request_report, CommentAction, ContractLabel, GithubClient, Summary, REPORT_MARKER,
+};
+use support::{StubResponse, StubServer};
+
+fn summary(error: usize, warning: usize) -> Summary {
+    Summary {
+        error,
+        warning,
+        info: 0,
+        waived: 0,
+        baselined: 0,
+    }
+}
+
+#[test]
+fn updates_marked_comment_and_swaps_state_label() {
+    let server = StubServer::start(|request| {
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/repos/owner/repo/issues/7/comments?per_page=100&page=1") => {
+                StubResponse::json(
+                    200,
+                    &format!(
+                        r#"[{{"id": 1, "body": "LGTM"}}, {{"id": 5, "body": "{REPORT_MARKER} copied", "user": {{"login": "someone", "type": "User"}}}}, {{"id": 42, "body": "{REPORT_MARKER}\n## 🔴 Contract Violation Detected", "user": {{"login": "Contract-Bot", "type": "User"}}}}]"#
+                    ),
+                )
+            }
+            ("GET", "/user") => StubResponse::json(200, r#"{"login": "contract-bot"}"#),
+            ("GET", "/repos/owner/repo/labels/contract%3Aviolation") => {
+                StubResponse::json(200, r#"{"name": "contract:violation", "color": "d73a49"}"#)
+            }
+            ("GET", "/repos/owner/repo/labels/contract%3Awarning") => {
+                StubResponse::json(200, r#"{"name": "contract:warning", "color": "ffffff"}"#)
+            }
+            ("GET", "/repos/owner/repo/issues/7/labels?per_page=100&page=1") => StubResponse::json(
+                200,
+                r#"[{"name": "bug", "color": "000000"}, {"name": "contract:violation", "color": "d73a49"}]"#,
+            ),
+            ("GET", _) => StubResponse::json(404, r#"{"message": "Not Found"}"#),
+            _ => StubResponse::json(200, "{}"),
+        }
+    });
+    let client = GithubClient::with_base_url(Some("token".to_string()), server.base_url.clone());
+    let body = format!("{REPORT_MARKER}\n## ✅ Contract Check Passed\n");
+
+    let report = publish_pull_request_report(&client, "owner/repo", 7, &body, &summary(0, 0))
+        .expect("publish");
+
+    assert_eq!(report.comment, CommentAction::Updated);
+    assert_eq!(report.label, ContractLabel::Valid);
+    assert_eq!(report.removed_labels, vec!["contract:violation"]);
+
+    let writes = server
+        .requests()
+        .into_iter()
+        .filter(|request| request.method != "GET")
+        .map(|request| (request.method, request.path, request.body))
+        .collect::<Vec<_>>();
+    let paths = writes
+        .iter()
+        .map(|(method, path, _)| format!("{method} {path}"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            "PATCH /repos/owner/repo/issues/comments/42",
+            "PATCH /repos/owner/repo/labels/contract%3Awarning",
+            "POST /repos/owner/repo/labels",
+            "DELETE /repos/owner/repo/issues/7/labels/contract%3Aviolation",
+            "POST /repos/owner/repo/issues/7/labels",
+        ]
+    );
+    let comment: serde_json::Value = serde_json::from_str(&writes[0].2).expect("comment json");
+    assert_eq!(comment["body"], body);
+    let created: serde_json::Value = serde_json::from_str(&writes[2].2).expect("label json");
+    assert_eq!(created["name"], "contract:valid");
+    assert_eq!(created["color"], "28a745");
+    let added: serde_json::Value = serde_json::from_str(&writes[4].2).expect("labels json");
+    assert_eq!(added["labels"][0], "contract:valid");
+}
+
+#[test]
+fn creates_comment_when_marked_comment_is_not_ours() {
+    let server = StubServer::start(|request| {
+        match (request.method.as_str(), request.path.as_str()) {
+            ("GET", "/repos/owner/repo/issues/3/comments?per_page=100&page=1") => {
+                StubResponse::json(
+                    200,
+                    &format!(
+                        r#"[{{"id": 1, "body": "LGTM"}}, {{"id": 2, "body": "{REPORT_MARKER}", "user": {{"login": "someone", "type": "User"}}}}]"#
+                    ),
+                )
+            }
+            ("GET", "/user") => StubResponse::json(
+                403,
+                r#"{"message": "Resource not accessible by integration"}"#,
+            ),
+            ("GET", "/repos/owner/repo/issues/3/labels?per_page=100&page=1") => {
+                StubResponse::json(200, r#"[{"name": "contract:warning", "color": "f9c513"}]"#)
+            }
+            ("GET", path) if path.starts_with("/repos/owner/repo/labels/") => {
+                StubResponse::json(200, r#"{"name": "label", "color": "D73A49"}"#)
+            }
+            _ => StubResponse::json(201, "{}"),
+        }
+    });
+    let client = GithubClient::with_base_url(Some("token".to_string()), server.base_url.clone());
+
+    let report = publish_pull_request_report(&client, "owner/repo", 3, "report", &summary(0, 2))
+        .expect("publish");
+
+    assert_eq!(report.comment, CommentAction::Created);
+    assert_eq!(report.label, ContractLabel::Warning);
+    assert!(report.removed_labels.is_empty());
+    let writes = server
+        .requests()
+        .into_iter()
+        .filter(|request| request.method != "GET")
+        .map(|request| format!("{} {}", request.method, request.path))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        writes,
+        vec![
+            "POST /repos/owner/repo/issues/3/comments",
+            "PATCH /repos/owner/repo/labels/contract%3Awarning",
+            "PATCH /repos/owner/repo/labels/contract%3Avalid",
+        ]
+    );
+}
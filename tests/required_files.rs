@@ -10,6 +10,7 @@ fn required_file(path: &str) -> RequiredFile {
         alternatives: Vec::new(),
         severity: Severity::Error,
         case_insensitive: false,
+        location: None,
     }
 }
 
@@ -21,6 +22,7 @@ fn required_pattern(pattern: &str) -> RequiredFile {
         alternatives: Vec::new(),
         severity: Severity::Error,
         case_insensitive: false,
+        location: None,
     }
 }
 
@@ -0,0 +1,150 @@
+use repo_contract::{result_schema_json, CheckResult, RuleId, RESULT_VERSION};
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 2
+required_files:
+  - path: "README.md"
+  - path: "LICENSE"
+    severity: "warning"
+"#;
+
+const STATE: &str = r#"{
+  "version": 1,
+  "repository": "owner/repo",
+  "branches": ["main"],
+  "protections": {
+    "main": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1
+      }
+    }
+  }
+}"#;
+
+fn run(command: &str) -> (Option<i32>, serde_json::Value) {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args([command, "--state", "state.json", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+    let value = serde_json::from_slice(&output.stdout).expect("json output");
+    (output.status.code(), value)
+}
+
+fn assert_matches_schema(value: &serde_json::Value) {
+    let schema: serde_json::Value =
+        serde_json::from_str(result_schema_json()).expect("result schema");
+    let compiled = jsonschema::JSONSchema::compile(&schema).expect("compile schema");
+    let messages = match compiled.validate(value) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors.map(|error| error.to_string()).collect::<Vec<_>>(),
+    };
+    assert!(
+        messages.is_empty(),
+        "result does not match schema: {messages:?}"
+    );
+}
+
+#[test]
+fn check_json_is_a_versioned_check_result() {
+    let (code, value) = run("check");
+
+    assert_eq!(code, Some(1));
+    assert_matches_schema(&value);
+    let result: CheckResult = serde_json::from_value(value).expect("check result");
+    assert_eq!(result.version, RESULT_VERSION);
+    assert!(!result.valid);
+    assert_eq!((result.summary.error, result.summary.warning), (1, 1));
+
+    let review = result
+        .findings
+        .iter()
+        .find(|finding| {
+            finding.path.as_deref()
+                == Some("required_pull_request_reviews.required_approving_review_count")
+        })
+        .expect("review count finding");
+    assert_eq!(review.rule, RuleId::BranchProtection);
+    assert_eq!(review.target, "main");
+    assert_eq!(review.code, "E011");
+    assert_eq!(
+        (review.expected.clone(), review.actual.clone()),
+        (2.into(), 1.into())
+    );
+    assert_eq!(
+        review.location.as_ref().map(|location| location.line),
+        Some(6)
+    );
+
+    let readme = result
+        .findings
+        .iter()
+        .find(|finding| finding.target == "README.md")
+        .expect("readme finding");
+    assert!(readme.passed);
+    assert_eq!(readme.code, "E001");
+    let license = result
+        .findings
+        .iter()
+        .find(|finding| finding.target == "LICENSE")
+        .expect("license finding");
+    assert_eq!((license.passed, license.code.as_str()), (false, "E001"));
+}
+
+#[test]
+fn diff_json_contains_only_failed_findings() {
+    let (code, value) = run("diff");
+
+    assert_eq!(code, Some(1));
+    assert_matches_schema(&value);
+    let result: CheckResult = serde_json::from_value(value).expect("check result");
+    assert_eq!(result.findings.len(), 2);
+    assert!(result.findings.iter().all(|finding| !finding.passed));
+}
+
+#[test]
+fn diff_yaml_matches_json_and_waived_differences_exit_zero() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        r#"version: "1.0"
+required_files:
+  - path: "LICENSE"
+exceptions:
+  - rule: required_files
+    target: "LICENSE"
+    reason: "Licensing is under legal review"
+    owner: "@alice"
+    expires: "2999-09-30"
+"#,
+    )
+    .expect("write contract");
+    let diff = |format: &str| {
+        Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+            .current_dir(temp.path())
+            .args(["diff", "--rules", "required_files", "--format", format])
+            .output()
+            .expect("run repo-contract")
+    };
+
+    let json = diff("json");
+    let yaml = diff("yaml");
+
+    assert_eq!(json.status.code(), Some(0));
+    assert_eq!(yaml.status.code(), Some(0));
+    let from_json: CheckResult = serde_json::from_slice(&json.stdout).expect("json result");
+    let from_yaml: CheckResult = serde_yaml::from_slice(&yaml.stdout).expect("yaml result");
+    assert_eq!(from_json, from_yaml);
+    assert_eq!(from_json.summary.waived, 1);
+    assert_eq!(from_json.findings[0].code, "E001");
+}
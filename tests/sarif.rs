@@ -0,0 +1,52 @@
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+
+required_files:
+  - path: "README.md"
+  # license is mandatory
+  - path: "LICENSE"
+    severity: "warning"
+  - pattern: "docs/*.md"
+"#;
+
+#[test]
+fn check_emits_sarif_with_contract_locations() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("README.md"), "# readme").expect("write readme");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["check", "--rules", "required_files", "--format", "sarif"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(1));
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout).expect("sarif json");
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    let rule_ids = run["tool"]["driver"]["rules"]
+        .as_array()
+        .expect("rules")
+        .iter()
+        .map(|rule| rule["id"].as_str().expect("id"))
+        .collect::<Vec<_>>();
+    assert!(rule_ids.contains(&"E001") && rule_ids.contains(&"E011"));
+    assert!(!rule_ids.contains(&"W002"));
+
+    let results = run["results"].as_array().expect("results");
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["ruleId"], "E001");
+    assert_eq!(results[0]["level"], "warning");
+    let location = &results[0]["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "contract.yml");
+    assert_eq!(location["region"]["startLine"], 6);
+    assert_eq!(location["region"]["startColumn"], 5);
+    assert_eq!(results[1]["ruleId"], "E002");
+    assert_eq!(results[1]["level"], "error");
+    assert_eq!(
+        results[1]["locations"][0]["physicalLocation"]["region"]["startLine"],
+        8
+    );
+}
@@ -0,0 +1,272 @@
+use repo_contract::{compose_severity_maps, Severity, SeverityMap};
+use std::process::Command;
+
+const CONTRACT: &str = r#"version: "1.0"
+branch_protection:
+  branches: ["main", "release/*"]
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 2
+    required_signatures: true
+  severity:
+    required_pull_request_reviews.dismiss_stale_reviews: error
+    required_signatures: info
+  branch_severity:
+    - branches: ["release/*"]
+      severity: warning
+      fields:
+        required_signatures: info
+"#;
+
+const STATE: &str = r#"{
+  "version": 1,
+  "repository": "owner/repo",
+  "branches": ["main", "release/1.0"],
+  "protections": {
+    "main": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1,
+        "dismiss_stale_reviews": false
+      }
+    },
+    "release/1.0": {
+      "required_pull_request_reviews": {
+        "enabled": true,
+        "required_approving_review_count": 1,
+        "dismiss_stale_reviews": false
+      }
+    }
+  }
+}"#;
+
+fn failures(temp: &tempfile::TempDir) -> Vec<(String, String, String)> {
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args([
+            "check",
+            "--state",
+            "state.json",
+            "--rules",
+            "branch_protection",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("run repo-contract");
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    result["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .filter(|finding| finding["passed"] == false)
+        .map(|finding| {
+            let text = |key: &str| finding[key].as_str().unwrap_or_default().to_string();
+            (text("target"), text("path"), text("severity"))
+        })
+        .collect()
+}
+
+fn expected(rows: &[(&str, &str, &str)]) -> Vec<(String, String, String)> {
+    rows.iter()
+        .map(|(target, path, severity)| {
+            (target.to_string(), path.to_string(), severity.to_string())
+        })
+        .collect()
+}
+
+#[test]
+fn contract_overrides_severity_per_field_and_branch_pattern() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), CONTRACT).expect("write contract");
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+
+    assert_eq!(
+        failures(&temp),
+        expected(&[
+            (
+                "main",
+                "required_pull_request_reviews.required_approving_review_count",
+                "error"
+            ),
+            (
+                "main",
+                "required_pull_request_reviews.dismiss_stale_reviews",
+                "error"
+            ),
+            ("main", "required_signatures", "info"),
+            (
+                "release/1.0",
+                "required_pull_request_reviews.required_approving_review_count",
+                "warning"
+            ),
+            (
+                "release/1.0",
+                "required_pull_request_reviews.dismiss_stale_reviews",
+                "warning"
+            ),
+            ("release/1.0", "required_signatures", "info"),
+        ])
+    );
+
+    std::fs::write(
+        temp.path().join(".contract.toml"),
+        "[severity_map]\ninfo = \"warning\"\n",
+    )
+    .expect("write config");
+    let severities = failures(&temp)
+        .into_iter()
+        .filter(|(_, path, _)| path == "required_signatures")
+        .map(|(_, _, severity)| severity)
+        .collect::<Vec<_>>();
+    assert_eq!(severities, vec!["warning", "warning"]);
+}
+
+#[test]
+fn validate_rejects_unknown_severity_fields() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        "version: \"1.0\"\nbranch_protection:\n  severity:\n    required_reviews: error\n",
+    )
+    .expect("write contract");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["validate"])
+        .output()
+        .expect("run repo-contract");
+
+    assert_eq!(output.status.code(), Some(1));
+}
+
+fn required_file_severities(contract: &str, global: &str) -> Vec<(String, String)> {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), contract).expect("write contract");
+    std::fs::write(temp.path().join(".contract.toml"), global).expect("write config");
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["check", "--rules", "required_files", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json output");
+    result["findings"]
+        .as_array()
+        .expect("findings")
+        .iter()
+        .map(|finding| {
+            (
+                finding["target"].as_str().expect("target").to_string(),
+                finding["severity"].as_str().expect("severity").to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn severity_maps_compose_without_chaining_and_global_wins() {
+    let contract = SeverityMap::from([
+        (Severity::Info, Severity::Warning),
+        (Severity::Warning, Severity::Info),
+    ]);
+    let global = SeverityMap::from([(Severity::Warning, Severity::Error)]);
+    assert_eq!(
+        compose_severity_maps(&contract, &global),
+        SeverityMap::from([
+            (Severity::Info, Severity::Warning),
+            (Severity::Warning, Severity::Error),
+        ])
+    );
+
+    let severities = required_file_severities(
+        r#"version: "1.0"
+severity_map:
+  info: warning
+  warning: info
+required_files:
+  - path: "AGENTS.md"
+    severity: info
+  - path: "SECURITY.md"
+    severity: warning
+"#,
+        "[severity_map]\nwarning = \"error\"\n",
+    );
+
+    assert_eq!(
+        severities,
+        vec![
+            ("AGENTS.md".to_string(), "warning".to_string()),
+            ("SECURITY.md".to_string(), "error".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn expired_waivers_escalate_after_severity_maps() {
+    let severities = required_file_severities(
+        r#"version: "1.0"
+severity_map:
+  error: info
+required_files:
+  - path: "LICENSE"
+  - path: "SECURITY.md"
+exceptions:
+  - rule: required_files
+    target: "LICENSE"
+    reason: "Licensing is under legal review"
+    owner: "@alice"
+    expires: "2000-01-01"
+"#,
+        "[severity_map]\ninfo = \"warning\"\n",
+    );
+
+    assert_eq!(
+        severities,
+        vec![
+            ("LICENSE".to_string(), "error".to_string()),
+            ("SECURITY.md".to_string(), "info".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn invalid_branch_severity_globs_are_reported() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        temp.path().join("contract.yml"),
+        CONTRACT.replace(
+            r#"- branches: ["release/*"]"#,
+            r#"- branches: ["release/[1"]"#,
+        ),
+    )
+    .expect("write contract");
+    std::fs::write(temp.path().join("state.json"), STATE).expect("write state");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args(["validate", "--format", "json"])
+        .output()
+        .expect("run repo-contract");
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let error = &json["files"][0]["errors"][0];
+    assert_eq!(
+        error["instance_path"],
+        "/branch_protection/branch_severity/0/branches/0"
+    );
+    assert_eq!(error["location"]["line"], 12);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .args([
+            "check",
+            "--state",
+            "state.json",
+            "--rules",
+            "branch_protection",
+        ])
+        .output()
+        .expect("run repo-contract");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid branch glob"));
+}
@@ -0,0 +1,75 @@
+use repo_contract::{
+    check_branch_protection, load_snapshot, summarize_branch_protection, BranchProtection,
+    BranchProtectionRules, ForgeKind, RepositorySnapshot, SNAPSHOT_VERSION,
+};
+use std::collections::BTreeMap;
+
+fn snapshot(protections: BTreeMap<String, Option<BranchProtectionRules>>) -> RepositorySnapshot {
+    RepositorySnapshot {
+        version: SNAPSHOT_VERSION,
+        forge: ForgeKind::Github,
+        repository: "owner/repo".to_string(),
+        branches: protections.keys().cloned().collect(),
+        protections,
+        settings: None,
+    }
+}
+
+fn branch_protection(branches: &[&str]) -> BranchProtection {
+    BranchProtection {
+        branches: branches.iter().map(|branch| branch.to_string()).collect(),
+        rules: BranchProtectionRules::default(),
+        severity: Default::default(),
+        branch_severity: Vec::new(),
+        locations: Default::default(),
+    }
+}
+
+#[test]
+fn evaluates_matched_branches_from_snapshot() {
+    let mut protections = BTreeMap::new();
+    protections.insert("main".to_string(), Some(BranchProtectionRules::default()));
+    protections.insert("release/1.0".to_string(), None);
+    protections.insert("feature/x".to_string(), None);
+
+    let state = snapshot(protections);
+    let reports = check_branch_protection(
+        &state,
+        &state.repository,
+        &branch_protection(&["main", "release/*"]),
+    )
+    .expect("check");
+
+    let targets = reports
+        .iter()
+        .map(|report| report.target.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(targets, vec!["main", "release/1.0"]);
+    assert!(reports[0].checks.is_empty());
+    assert_eq!(reports[1].checks[0].path, "branch_protection");
+    assert_eq!(summarize_branch_protection(&reports).error, 1);
+}
+
+#[test]
+fn missing_protection_entry_is_an_error() {
+    let mut state = snapshot(BTreeMap::new());
+    state.branches.push("main".to_string());
+
+    let result = check_branch_protection(&state, "owner/repo", &branch_protection(&["main"]));
+    assert!(result.is_err());
+}
+
+#[test]
+fn snapshot_round_trips_through_file() {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    let path = temp.path().join("state.json");
+    let mut protections = BTreeMap::new();
+    protections.insert("main".to_string(), Some(BranchProtectionRules::default()));
+    let state = snapshot(protections);
+    std::fs::write(&path, serde_json::to_string(&state).expect("serialize")).expect("write");
+
+    let loaded = load_snapshot(&path).expect("load");
+    assert_eq!(loaded.repository, "owner/repo");
+    assert_eq!(loaded.branches, vec!["main".to_string()]);
+    assert!(loaded.protections["main"].is_some());
+}
@@ -0,0 +1,233 @@
+use std::process::Command;
+
+fn run(temp: &tempfile::TempDir, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_repo-contract"))
+        .current_dir(temp.path())
+        .env_remove("GITHUB_ACTIONS")
+        .args(args)
+        .output()
+        .expect("run repo-contract")
+}
+
+fn workspace(contract: &str) -> tempfile::TempDir {
+    let temp = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(temp.path().join("contract.yml"), contract).expect("write contract");
+    temp
+}
+
+#[test]
+fn critical_tier_applies_preset_and_escalates_warnings() {
+    let temp = workspace(
+        r#"version: "1.0"
+metadata:
+  tier: critical
+branch_protection:
+  rules:
+    required_pull_request_reviews:
+      required_approving_review_count: 3
+required_files:
+  - path: "CONTRIBUTING.md"
+    severity: warning
+"#,
+    );
+
+    let output = run(
+        &temp,
+        &["validate", "--merged", "--print-effective", "json"],
+    );
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let rules = &json["branch_protection"]["rules"];
+    assert_eq!(
+        rules["required_pull_request_reviews"]["required_approving_review_count"],
+        3
+    );
+    assert_eq!(rules["enforce_admins"], true);
+    assert_eq!(rules["required_signatures"], true);
+    assert_eq!(json["severity_map"]["warning"], "error");
+
+    let output = run(
+        &temp,
+        &["check", "--rules", "required_files", "--format", "json"],
+    );
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["summary"]["error"], 1);
+    assert_eq!(json["findings"][0]["severity"], "error");
+}
+
+#[test]
+fn experimental_tier_downgrades_errors() {
+    let temp = workspace(
+        r#"version: "1.0"
+metadata:
+  tier: experimental
+required_files:
+  - path: "LICENSE"
+"#,
+    );
+
+    let output = run(
+        &temp,
+        &["check", "--rules", "required_files", "--format", "json"],
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["summary"]["warning"], 1);
+}
+
+#[test]
+fn user_defined_tiers_and_unknown_tiers() {
+    let temp = workspace(
+        r#"version: "1.0"
+tiers:
+  regulated:
+    required_files:
+      - path: "SECURITY.md"
+metadata:
+  tier: regulated
+"#,
+    );
+    let output = run(
+        &temp,
+        &["validate", "--merged", "--print-effective", "json"],
+    );
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(json["required_files"][0]["path"], "SECURITY.md");
+
+    let temp = workspace("version: \"1.0\"\nmetadata:\n  tier: legendary\n");
+    let output = run(&temp, &["validate", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let error = &json["files"][0]["errors"][0];
+    assert_eq!(error["instance_path"], "/metadata/tier");
+    assert_eq!(error["location"]["line"], 3);
+}
+
+#[test]
+fn preset_branches_and_checks_are_replaced_not_concatenated() {
+    let temp = workspace(
+        r#"version: "1.0"
+tiers:
+  service:
+    branch_protection:
+      branches: ["main", "release/*"]
+      rules:
+        required_linear_history: true
+        required_status_checks:
+          checks:
+            - context: "ci/build"
+            - context: "ci/lint"
+metadata:
+  tier: service
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_status_checks:
+      checks:
+        - context: "ci/build"
+"#,
+    );
+
+    let output = run(
+        &temp,
+        &["validate", "--merged", "--print-effective", "json"],
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let protection = &json["branch_protection"];
+    assert_eq!(protection["branches"], serde_json::json!(["main"]));
+    assert_eq!(
+        protection["rules"]["required_status_checks"]["checks"],
+        serde_json::json!([{ "context": "ci/build", "app_id": null }])
+    );
+    assert_eq!(protection["rules"]["required_linear_history"], true);
+}
+
+#[test]
+fn preset_required_files_keep_contract_locations() {
+    let temp = workspace(
+        r#"version: "1.0"
+tiers:
+  regulated:
+    required_files:
+      - path: "SECURITY.md"
+      - path: "LICENSE"
+metadata:
+  tier: regulated
+required_files:
+  - path: "LICENSE"
+  - path: "CODEOWNERS"
+"#,
+    );
+
+    let output = run(
+        &temp,
+        &["check", "--rules", "required_files", "--format", "json"],
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let findings = json["findings"].as_array().expect("findings");
+    let lines = findings
+        .iter()
+        .map(|finding| {
+            (
+                finding["target"].as_str().unwrap_or_default().to_string(),
+                finding["location"]["line"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            ("LICENSE".to_string(), serde_json::json!(10)),
+            ("CODEOWNERS".to_string(), serde_json::json!(11)),
+            ("SECURITY.md".to_string(), serde_json::Value::Null),
+        ]
+    );
+}
+
+#[test]
+fn base_tier_preset_applies_over_profile_branch_protection() {
+    let temp = workspace(
+        r#"version: "1.0"
+profile: "team"
+metadata:
+  tier: critical
+"#,
+    );
+    std::fs::write(
+        temp.path().join("contract.team.yml"),
+        r#"version: "1.0"
+branch_protection:
+  branches: ["main"]
+  rules:
+    required_linear_history: true
+"#,
+    )
+    .expect("write profile");
+
+    let output = run(
+        &temp,
+        &["validate", "--merged", "--print-effective", "json"],
+    );
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let rules = &json["branch_protection"]["rules"];
+    assert_eq!(
+        rules["required_pull_request_reviews"]["required_approving_review_count"],
+        2
+    );
+    assert_eq!(rules["enforce_admins"], true);
+    assert_eq!(rules["required_signatures"], true);
+    assert_eq!(rules["required_linear_history"], true);
+
+    let output = run(&temp, &["explain", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"tier_preset\": \"critical\""), "{stdout}");
+}